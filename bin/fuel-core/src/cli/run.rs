@@ -18,6 +18,7 @@ use fuel_core::{
         CombinedDatabase,
         CombinedDatabaseConfig,
     },
+    fuel_core_graphql_api::database::ReadViewConfig,
     producer::Config as ProducerConfig,
     service::{
         config::Trigger,
@@ -124,6 +125,17 @@ pub struct Command {
     #[arg(name = "DB_PRUNE", long = "db-prune", env, default_value = "false")]
     pub db_prune: bool,
 
+    /// Verifies after the genesis import that no coin from the snapshot uses
+    /// the nonce of a message from the snapshot as its transaction id, and that
+    /// every contract with the latest UTXO has the bytecode.
+    #[arg(long = "genesis-cross-checks", env, default_value = "false")]
+    pub genesis_cross_checks: bool,
+
+    /// Skips the verification of the group checksums from the snapshot metadata
+    /// during the genesis import. It speeds up the import of trusted snapshots.
+    #[arg(long = "genesis-skip-group-checksums", env, default_value = "false")]
+    pub genesis_skip_group_checksums: bool,
+
     /// Should be used for local development only. Enabling debug mode:
     /// - Allows GraphQL Endpoints to arbitrarily advance blocks.
     /// - Enables debugger GraphQL Endpoints.
@@ -204,6 +216,41 @@ pub struct Command {
     #[clap(long = "api-request-timeout", default_value = "30m", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The number of times the API retries a read failed because of the database.
+    /// The reads are retried immediately.
+    #[clap(long = "api-read-retries", default_value = "0", env)]
+    pub api_read_retries: usize,
+
+    /// The maximum size of the message data in bytes for which the API generates
+    /// the message proof. The size is not limited if it is not set.
+    #[clap(long = "api-max-message-data-size", env)]
+    pub api_max_message_data_size: Option<usize>,
+
+    /// Logs a warning on every API read if the on-chain and off-chain databases
+    /// diverge. Meant for debugging, it shouldn't be enabled in production.
+    #[clap(long = "api-debug-view-consistency", env)]
+    pub api_debug_view_consistency: bool,
+
+    /// The maximum difference between the latest on-chain and off-chain heights
+    /// for which the API serves requests. Requests fail if the databases diverge further.
+    #[clap(long = "api-max-height-skew", default_value = "1", env)]
+    pub api_max_height_skew: u32,
+
+    /// The number of commit block heights for which the API caches the message proofs.
+    /// The cache is disabled if it is zero.
+    #[clap(long = "api-message-proof-cache-size", default_value = "32", env)]
+    pub api_message_proof_cache_size: usize,
+
+    /// The number of message proofs the API caches per commit block height.
+    /// The cache is disabled if it is zero.
+    #[clap(long = "api-message-proofs-per-commit", default_value = "256", env)]
+    pub api_message_proofs_per_commit: usize,
+
+    /// The number of blocks below the latest height for which the API keeps
+    /// the cached message proofs.
+    #[clap(long = "api-message-proof-cache-horizon", default_value = "1024", env)]
+    pub api_message_proof_cache_horizon: u32,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
 }
@@ -219,6 +266,8 @@ impl Command {
             database_type,
             db_prune,
             snapshot,
+            genesis_cross_checks,
+            genesis_skip_group_checksums,
             vm_backtrace,
             debug,
             utxo_validation,
@@ -241,6 +290,13 @@ impl Command {
             time_until_synced,
             query_log_threshold_time,
             api_request_timeout,
+            api_read_retries,
+            api_max_message_data_size,
+            api_debug_view_consistency,
+            api_max_height_skew,
+            api_message_proof_cache_size,
+            api_message_proofs_per_commit,
+            api_message_proof_cache_horizon,
             profiling: _,
         } = self;
 
@@ -252,7 +308,8 @@ impl Command {
                 let metadata = SnapshotMetadata::read(path)?;
                 SnapshotReader::open(metadata)?
             }
-        };
+        }
+        .with_group_checksum_verification(!genesis_skip_group_checksums);
         let chain_config = snapshot_reader.chain_config();
 
         #[cfg(feature = "relayer")]
@@ -332,6 +389,10 @@ impl Command {
             api_request_timeout: api_request_timeout.into(),
             combined_db_config,
             snapshot_reader,
+            genesis_cross_checks,
+            genesis_validators: Default::default(),
+            genesis_import_scope: Default::default(),
+            genesis_handler_config: Default::default(),
             debug,
             native_executor_version,
             utxo_validation,
@@ -366,6 +427,15 @@ impl Command {
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            read_view: ReadViewConfig {
+                max_retries: api_read_retries,
+                max_message_data_size: api_max_message_data_size,
+                check_consistency: api_debug_view_consistency,
+                max_height_skew: api_max_height_skew,
+                message_proof_cache_size: api_message_proof_cache_size,
+                message_proofs_per_commit: api_message_proofs_per_commit,
+                message_proof_cache_horizon: api_message_proof_cache_horizon,
+            },
         };
         Ok(config)
     }
@@ -1,5 +1,7 @@
 use anyhow::Context;
+use fuel_core_types::fuel_types::Bytes32;
 use std::{
+    collections::BTreeMap,
     io::Read,
     path::{
         Path,
@@ -64,6 +66,12 @@ impl TableEncoding {
 pub struct SnapshotMetadata {
     pub chain_config: PathBuf,
     pub table_encoding: TableEncoding,
+    /// The expected checksums of the groups of each table, see [`crate::GroupChecksum`].
+    /// The parquet snapshots carry the checksum of every group. The groups of the json
+    /// snapshots are formed when they are read, so they don't have checksums.
+    /// Tables without checksums are imported without verification.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub group_checksums: BTreeMap<String, Vec<Bytes32>>,
 }
 
 impl SnapshotMetadata {
@@ -117,6 +125,7 @@ mod tests {
                 table_encoding: TableEncoding::Json {
                     filepath: "some_state_file.json".into(),
                 },
+                group_checksums: Default::default(),
             };
             serde_json::to_writer(
                 std::fs::File::create(dir.join("metadata.json")).unwrap(),
@@ -134,7 +143,8 @@ mod tests {
                     chain_config: dir.join("some_chain_config.json"),
                     table_encoding: TableEncoding::Json {
                         filepath: temp_dir.path().join("some_state_file.json"),
-                    }
+                    },
+                    group_checksums: Default::default(),
                 }
             );
         }
@@ -149,6 +159,7 @@ mod tests {
                 table_encoding: TableEncoding::Json {
                     filepath: dir.join("some_state_file.json"),
                 },
+                group_checksums: Default::default(),
             };
 
             // when
@@ -165,7 +176,8 @@ mod tests {
                     chain_config: "some_chain_config.json".into(),
                     table_encoding: TableEncoding::Json {
                         filepath: "some_state_file.json".into(),
-                    }
+                    },
+                    group_checksums: Default::default(),
                 }
             );
         }
@@ -188,6 +200,7 @@ mod tests {
                     )]),
                     latest_block_config_path: "latest_block_config.parquet".into(),
                 },
+                group_checksums: Default::default(),
             };
             serde_json::to_writer(
                 std::fs::File::create(dir.join("metadata.json")).unwrap(),
@@ -211,7 +224,8 @@ mod tests {
                         latest_block_config_path: temp_dir
                             .path()
                             .join("latest_block_config.parquet"),
-                    }
+                    },
+                    group_checksums: Default::default(),
                 }
             );
         }
@@ -230,6 +244,7 @@ mod tests {
                     )]),
                     latest_block_config_path: dir.join("latest_block_config.parquet"),
                 },
+                group_checksums: Default::default(),
             };
 
             // when
@@ -250,7 +265,8 @@ mod tests {
                             "coins.parquet".into(),
                         )]),
                         latest_block_config_path: "latest_block_config.parquet".into(),
-                    }
+                    },
+                    group_checksums: Default::default(),
                 }
             );
         }
@@ -1,9 +1,13 @@
-use std::fmt::Debug;
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+};
 
 use fuel_core_storage::{
     structured_storage::TableWithBlueprint,
     Mappable,
 };
+use fuel_core_types::fuel_types::Bytes32;
 use itertools::Itertools;
 
 use crate::{
@@ -62,6 +66,20 @@ where
     },
 }
 
+#[cfg(feature = "parquet")]
+fn decode_group<T>(
+    byte_group: anyhow::Result<Vec<Vec<u8>>>,
+) -> anyhow::Result<Vec<TableEntry<T>>>
+where
+    T: Mappable,
+    TableEntry<T>: serde::de::DeserializeOwned,
+{
+    byte_group?
+        .into_iter()
+        .map(|group| postcard::from_bytes(&group).map_err(|e| anyhow::anyhow!(e)))
+        .collect()
+}
+
 #[cfg(feature = "parquet")]
 impl<T> Iterator for GroupIter<T>
 where
@@ -73,17 +91,16 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         match self {
             GroupIter::InMemory { groups } => groups.next(),
-            GroupIter::Parquet { decoder } => {
-                let group = decoder.next()?.and_then(|byte_group| {
-                    byte_group
-                        .into_iter()
-                        .map(|group| {
-                            postcard::from_bytes(&group).map_err(|e| anyhow::anyhow!(e))
-                        })
-                        .collect()
-                });
-                Some(group)
-            }
+            GroupIter::Parquet { decoder } => Some(decode_group(decoder.next()?)),
+        }
+    }
+
+    /// Skips the groups without decoding them, so the resumed import
+    /// doesn't read the groups that are already imported.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            GroupIter::InMemory { groups } => groups.nth(n),
+            GroupIter::Parquet { decoder } => Some(decode_group(decoder.nth(n)?)),
         }
     }
 }
@@ -100,6 +117,12 @@ where
             GroupIter::InMemory { groups } => groups.next(),
         }
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            GroupIter::InMemory { groups } => groups.nth(n),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -119,6 +142,8 @@ enum DataSource {
 pub struct SnapshotReader {
     chain_config: ChainConfig,
     data_source: DataSource,
+    group_checksums: BTreeMap<String, Vec<Bytes32>>,
+    verify_group_checksums: bool,
 }
 
 impl SnapshotReader {
@@ -129,6 +154,8 @@ impl SnapshotReader {
                 state,
                 group_size: MAX_GROUP_SIZE,
             },
+            group_checksums: Default::default(),
+            verify_group_checksums: true,
         }
     }
 
@@ -152,6 +179,7 @@ impl SnapshotReader {
                 state: state_config,
                 group_size: MAX_GROUP_SIZE,
             },
+            group_checksums: Default::default(),
             ..self
         }
     }
@@ -176,6 +204,8 @@ impl SnapshotReader {
         Ok(Self {
             data_source: DataSource::InMemory { state, group_size },
             chain_config,
+            group_checksums: Default::default(),
+            verify_group_checksums: true,
         })
     }
 
@@ -192,6 +222,8 @@ impl SnapshotReader {
                 latest_block_config,
             },
             chain_config,
+            group_checksums: Default::default(),
+            verify_group_checksums: true,
         })
     }
 
@@ -228,7 +260,7 @@ impl SnapshotReader {
         use crate::TableEncoding;
         let chain_config = ChainConfig::from_snapshot_metadata(&snapshot_metadata)?;
 
-        match snapshot_metadata.table_encoding {
+        let reader = match snapshot_metadata.table_encoding {
             TableEncoding::Json { filepath } => {
                 Self::json(filepath, chain_config, json_group_size)
             }
@@ -238,7 +270,30 @@ impl SnapshotReader {
                 latest_block_config_path,
                 ..
             } => Self::parquet(tables, latest_block_config_path, chain_config),
+        }?;
+
+        Ok(Self {
+            group_checksums: snapshot_metadata.group_checksums,
+            ..reader
+        })
+    }
+
+    /// Enables or disables the verification of the group checksums
+    /// from the snapshot metadata during the import. Enabled by default.
+    pub fn with_group_checksum_verification(self, enabled: bool) -> Self {
+        Self {
+            verify_group_checksums: enabled,
+            ..self
+        }
+    }
+
+    /// Returns the expected checksums of the groups of the `table`, if the snapshot
+    /// provides them and the verification is enabled.
+    pub fn group_checksums(&self, table: &str) -> Option<&[Bytes32]> {
+        if !self.verify_group_checksums {
+            return None
         }
+        self.group_checksums.get(table).map(Vec::as_slice)
     }
 
     pub fn read<T>(&self) -> anyhow::Result<Groups<T>>
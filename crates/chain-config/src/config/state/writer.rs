@@ -8,7 +8,11 @@ use crate::{
     TableEncoding,
 };
 use fuel_core_storage::structured_storage::TableWithBlueprint;
-use std::path::PathBuf;
+use fuel_core_types::fuel_types::Bytes32;
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+};
 
 #[cfg(feature = "parquet")]
 use super::parquet;
@@ -157,6 +161,7 @@ enum FragmentData {
     #[cfg(feature = "parquet")]
     Parquet {
         tables: std::collections::HashMap<String, PathBuf>,
+        group_checksums: BTreeMap<String, Vec<Bytes32>>,
         compression: ZstdCompressionLevel,
     },
 }
@@ -176,14 +181,17 @@ impl FragmentData {
             (
                 FragmentData::Parquet {
                     tables,
+                    group_checksums,
                     compression,
                 },
                 FragmentData::Parquet {
                     tables: their_tables,
+                    group_checksums: their_group_checksums,
                     compression: their_compression,
                 },
             ) => {
                 tables.extend(their_tables);
+                group_checksums.extend(their_group_checksums);
                 anyhow::ensure!(*compression == their_compression, "Fragments use different compressions.")
             }
             #[cfg(feature="parquet")]
@@ -211,7 +219,7 @@ impl SnapshotFragment {
         latest_block_config: Option<LastBlockConfig>,
         chain_config: &ChainConfig,
     ) -> anyhow::Result<SnapshotMetadata> {
-        let table_encoding = match self.data {
+        let (table_encoding, group_checksums) = match self.data {
             FragmentData::Json { builder } => {
                 let state_config = builder.build(latest_block_config)?;
                 std::fs::create_dir_all(&self.dir)?;
@@ -219,13 +227,15 @@ impl SnapshotFragment {
                 let file = std::fs::File::create(&state_file_path)?;
                 serde_json::to_writer_pretty(file, &state_config)?;
 
-                TableEncoding::Json {
+                let table_encoding = TableEncoding::Json {
                     filepath: state_file_path,
-                }
+                };
+                (table_encoding, BTreeMap::new())
             }
             #[cfg(feature = "parquet")]
             FragmentData::Parquet {
                 tables,
+                group_checksums,
                 compression,
             } => {
                 let latest_block_config_path =
@@ -236,10 +246,11 @@ impl SnapshotFragment {
                     compression,
                 )?;
 
-                TableEncoding::Parquet {
+                let table_encoding = TableEncoding::Parquet {
                     tables,
                     latest_block_config_path,
-                }
+                };
+                (table_encoding, group_checksums)
             }
         };
 
@@ -247,6 +258,7 @@ impl SnapshotFragment {
             &self.dir,
             chain_config,
             table_encoding,
+            group_checksums,
         )
     }
 }
@@ -349,6 +361,7 @@ impl SnapshotWriter {
         dir: &std::path::Path,
         chain_config: &ChainConfig,
         table_encoding: TableEncoding,
+        group_checksums: BTreeMap<String, Vec<Bytes32>>,
     ) -> anyhow::Result<SnapshotMetadata> {
         let chain_config_path = dir.join(Self::CHAIN_CONFIG_FILENAME);
         chain_config.write(&chain_config_path)?;
@@ -356,6 +369,7 @@ impl SnapshotWriter {
         let metadata = SnapshotMetadata {
             chain_config: chain_config_path,
             table_encoding,
+            group_checksums,
         };
         metadata.clone().write(dir)?;
         Ok(metadata)
@@ -370,9 +384,10 @@ impl SnapshotWriter {
                 compression,
                 ..
             } => {
-                let tables = table_encoders.close()?;
+                let (tables, group_checksums) = table_encoders.close()?;
                 FragmentData::Parquet {
                     tables,
+                    group_checksums,
                     compression,
                 }
             }
@@ -389,12 +404,18 @@ impl SnapshotWriter {
 struct PostcardParquetEncoder {
     path: PathBuf,
     encoder: parquet::encode::Encoder<std::fs::File>,
+    /// The checksums of the written groups, one per row group.
+    group_checksums: Vec<Bytes32>,
 }
 
 #[cfg(feature = "parquet")]
 impl PostcardParquetEncoder {
     pub fn new(path: PathBuf, encoder: parquet::encode::Encoder<std::fs::File>) -> Self {
-        Self { path, encoder }
+        Self {
+            path,
+            encoder,
+            group_checksums: vec![],
+        }
     }
 
     fn write<T>(&mut self, elements: Vec<TableEntry<T>>) -> anyhow::Result<()>
@@ -407,6 +428,9 @@ impl PostcardParquetEncoder {
             .into_iter()
             .map(|entry| postcard::to_stdvec(&entry))
             .try_collect()?;
+        let mut checksum = crate::GroupChecksum::default();
+        encoded.iter().for_each(|entry| checksum.add_encoded(entry));
+        self.group_checksums.push(checksum.finalize());
         self.encoder.write(encoded)
     }
 }
@@ -452,13 +476,21 @@ impl TableEncoders {
         Ok(encoder)
     }
 
-    fn close(self) -> anyhow::Result<std::collections::HashMap<String, PathBuf>> {
+    /// Closes the encoders and returns the files and the group checksums of the tables.
+    fn close(
+        self,
+    ) -> anyhow::Result<(
+        std::collections::HashMap<String, PathBuf>,
+        BTreeMap<String, Vec<Bytes32>>,
+    )> {
         let mut files = std::collections::HashMap::new();
-        for (file, encoder) in self.encoders {
+        let mut group_checksums = BTreeMap::new();
+        for (table, encoder) in self.encoders {
             encoder.encoder.close()?;
-            files.insert(file, encoder.path);
+            group_checksums.insert(table.clone(), encoder.group_checksums);
+            files.insert(table, encoder.path);
         }
-        Ok(files)
+        Ok((files, group_checksums))
     }
 }
 
@@ -571,6 +603,39 @@ mod tests {
         insta::assert_snapshot!(encoded_json);
     }
 
+    #[test]
+    fn parquet_snapshot_has_the_checksums_of_the_groups() {
+        // given
+        use crate::{
+            AsTable,
+            Randomize,
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = given_parquet_writer(dir.path());
+        let mut rng = StdRng::from_seed([0; 32]);
+        let coins: Vec<TableEntry<Coins>> = StateConfig::randomize(&mut rng).as_table();
+        let (first_group, second_group) = coins.split_at(coins.len() / 2);
+
+        // when
+        writer.write::<Coins>(first_group.to_vec()).unwrap();
+        writer.write::<Coins>(second_group.to_vec()).unwrap();
+        let snapshot = writer.close(None, &ChainConfig::local_testnet()).unwrap();
+
+        // then
+        let reader = crate::SnapshotReader::open(snapshot).unwrap();
+        let read_checksums: Vec<_> = reader
+            .read::<Coins>()
+            .unwrap()
+            .into_iter()
+            .map(|group| crate::group_checksum(&group.unwrap()).unwrap())
+            .collect();
+        assert_eq!(read_checksums.len(), 2);
+        assert_eq!(
+            reader.group_checksums(Coins::column().name()),
+            Some(read_checksums.as_slice())
+        );
+    }
+
     fn given_parquet_writer(path: &Path) -> SnapshotWriter {
         SnapshotWriter::parquet(path, ZstdCompressionLevel::Uncompressed).unwrap()
     }
@@ -1,4 +1,8 @@
 use fuel_core_storage::Mappable;
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::Bytes32,
+};
 
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct TableEntry<T>
@@ -89,3 +93,50 @@ where
         }
     }
 }
+
+/// The checksum of a group of the snapshot. It is the hash of the sorted hashes of the
+/// postcard-encoded entries of the group, so it doesn't depend on the order of the entries.
+/// It allows computing the checksum of the entries read back from the storage.
+#[derive(Debug, Default, Clone)]
+pub struct GroupChecksum {
+    entry_hashes: Vec<Bytes32>,
+}
+
+impl GroupChecksum {
+    /// Adds the postcard-encoded entry to the checksum.
+    pub fn add_encoded(&mut self, entry: &[u8]) {
+        self.entry_hashes.push(Hasher::hash(entry));
+    }
+
+    /// Adds the entry to the checksum.
+    pub fn add<T>(&mut self, entry: &TableEntry<T>) -> anyhow::Result<()>
+    where
+        T: Mappable,
+        TableEntry<T>: serde::Serialize,
+    {
+        let bytes = postcard::to_allocvec(entry).map_err(anyhow::Error::msg)?;
+        self.add_encoded(&bytes);
+        Ok(())
+    }
+
+    pub fn finalize(mut self) -> Bytes32 {
+        self.entry_hashes.sort_unstable();
+        self.entry_hashes
+            .iter()
+            .fold(Hasher::default(), |hasher, hash| hasher.chain(hash))
+            .finalize()
+    }
+}
+
+/// Computes the [`GroupChecksum`] of the `group`.
+pub fn group_checksum<T>(group: &[TableEntry<T>]) -> anyhow::Result<Bytes32>
+where
+    T: Mappable,
+    TableEntry<T>: serde::Serialize,
+{
+    let mut checksum = GroupChecksum::default();
+    for entry in group {
+        checksum.add(entry)?;
+    }
+    Ok(checksum.finalize())
+}
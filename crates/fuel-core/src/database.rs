@@ -31,6 +31,7 @@ use fuel_core_storage::{
     kv_store::{
         KVItem,
         KeyValueInspect,
+        StorageColumn,
         Value,
     },
     not_found,
@@ -154,6 +155,14 @@ impl<Description> Database<Description>
 where
     Description: DatabaseDescription,
 {
+    /// Returns the approximate size in bytes of each column of the database,
+    /// in the order of the columns.
+    pub fn table_sizes(&self) -> StorageResult<Vec<(&'static str, u64)>> {
+        enum_iterator::all::<Description::Column>()
+            .map(|column| Ok((column.name(), self.data.column_size(column)?)))
+            .collect()
+    }
+
     pub fn in_memory() -> Self {
         let data = Arc::<MemoryStore<Description>>::new(MemoryStore::default());
         Self {
@@ -521,6 +530,34 @@ mod tests {
             column_keys_not_exceed_count::<OnChain>();
         }
 
+        #[test]
+        fn table_sizes_reports_the_size_of_each_column() {
+            // Given
+            let mut database = Database::<OnChain>::default();
+            database
+                .storage_as_mut::<Coins>()
+                .insert(&UtxoId::default(), &CompressedCoin::default())
+                .unwrap();
+
+            // When
+            let sizes = database.table_sizes().unwrap();
+
+            // Then
+            assert_eq!(
+                sizes.len(),
+                enum_iterator::all::<<OnChain as DatabaseDescription>::Column>().count()
+            );
+            let size_of = |table: &str| {
+                sizes
+                    .iter()
+                    .find(|(name, _)| *name == table)
+                    .map(|(_, size)| *size)
+                    .unwrap()
+            };
+            assert!(size_of(Coins::column().name()) > 0);
+            assert_eq!(size_of(FuelBlocks::column().name()), 0);
+        }
+
         #[test]
         fn database_advances_with_a_new_block() {
             // Given
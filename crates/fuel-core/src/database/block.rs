@@ -34,10 +34,19 @@ use fuel_core_types::{
     },
     entities::relayer::message::MerkleProof,
     fuel_merkle::binary::MerkleTree,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
 };
 use itertools::Itertools;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::{
+        hash_map::Entry,
+        HashMap,
+    },
+};
 
 impl Database<OffChain> {
     pub fn get_block_height(&self, id: &BlockId) -> StorageResult<Option<BlockHeight>> {
@@ -94,26 +103,99 @@ impl Database {
         message_block_height: &BlockHeight,
         commit_block_height: &BlockHeight,
     ) -> StorageResult<MerkleProof> {
-        if message_block_height > commit_block_height {
-            Err(anyhow::anyhow!(
-                "The `message_block_height` is higher than `commit_block_height`"
-            ))?;
+        self.block_history_proofs_for_commit(
+            commit_block_height,
+            core::slice::from_ref(message_block_height),
+        )?
+        .pop()
+        .ok_or(anyhow::anyhow!("The proof for the message block is missing").into())
+    }
+
+    /// Returns the [`MerkleProof`] for each of the `message_block_heights` relatively
+    /// to the same commit block, in the same order. The Merkle tree of the commit
+    /// block is loaded once for all of them.
+    ///
+    /// Fails if any of the proofs can't be built.
+    pub fn block_history_proofs_for_commit(
+        &self,
+        commit_block_height: &BlockHeight,
+        message_block_heights: &[BlockHeight],
+    ) -> StorageResult<Vec<MerkleProof>> {
+        for message_block_height in message_block_heights {
+            ensure_message_not_after_commit(message_block_height, commit_block_height)?;
         }
 
-        let message_merkle_metadata = self
-            .storage::<FuelBlockMerkleMetadata>()
-            .get(&DenseMetadataKey::Primary(*message_block_height))?
-            .ok_or(not_found!(FuelBlockMerkleMetadata))?;
+        let tree = self.block_history_tree(commit_block_height)?;
+        message_block_heights
+            .iter()
+            .map(|message_block_height| {
+                self.block_history_proof_from_tree(&tree, message_block_height)
+            })
+            .collect()
+    }
+
+    /// Returns the [`MerkleProof`] for each `(message_block_height, commit_block_height)`
+    /// pair in the same order as the `requests`. The Merkle tree of the commit block is
+    /// loaded once and reused by all requests with the same commit block height.
+    ///
+    /// An error for one of the pairs is returned in its place and doesn't stop
+    /// the iteration.
+    pub fn block_history_proofs<'a>(
+        &'a self,
+        requests: &'a [(BlockHeight, BlockHeight)],
+    ) -> impl Iterator<Item = StorageResult<MerkleProof>> + 'a {
+        let mut trees = HashMap::new();
+        requests
+            .iter()
+            .map(move |(message_block_height, commit_block_height)| {
+                ensure_message_not_after_commit(
+                    message_block_height,
+                    commit_block_height,
+                )?;
+
+                let tree = match trees.entry(*commit_block_height) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        entry.insert(self.block_history_tree(commit_block_height)?)
+                    }
+                };
+                self.block_history_proof_from_tree(tree, message_block_height)
+            })
+    }
+
+    /// Returns the root of the block history Merkle tree at the commit block,
+    /// which the proofs of [`Self::block_history_proof`] are verified against.
+    pub fn block_history_root(
+        &self,
+        commit_block_height: &BlockHeight,
+    ) -> StorageResult<Bytes32> {
+        let root = self.storage::<FuelBlocks>().root(commit_block_height)?;
+        Ok(root.into())
+    }
 
+    fn block_history_tree(
+        &self,
+        commit_block_height: &BlockHeight,
+    ) -> StorageResult<MerkleTree<FuelBlockMerkleData, &Self>> {
         let commit_merkle_metadata = self
             .storage::<FuelBlockMerkleMetadata>()
             .get(&DenseMetadataKey::Primary(*commit_block_height))?
             .ok_or(not_found!(FuelBlockMerkleMetadata))?;
 
         let storage = self;
-        let tree: MerkleTree<FuelBlockMerkleData, _> =
-            MerkleTree::load(storage, commit_merkle_metadata.version())
-                .map_err(|err| StorageError::Other(anyhow::anyhow!(err)))?;
+        MerkleTree::load(storage, commit_merkle_metadata.version())
+            .map_err(|err| StorageError::Other(anyhow::anyhow!(err)))
+    }
+
+    fn block_history_proof_from_tree(
+        &self,
+        tree: &MerkleTree<FuelBlockMerkleData, &Self>,
+        message_block_height: &BlockHeight,
+    ) -> StorageResult<MerkleProof> {
+        let message_merkle_metadata = self
+            .storage::<FuelBlockMerkleMetadata>()
+            .get(&DenseMetadataKey::Primary(*message_block_height))?
+            .ok_or(not_found!(FuelBlockMerkleMetadata))?;
 
         let proof_index = message_merkle_metadata
             .version()
@@ -130,6 +212,18 @@ impl Database {
     }
 }
 
+fn ensure_message_not_after_commit(
+    message_block_height: &BlockHeight,
+    commit_block_height: &BlockHeight,
+) -> StorageResult<()> {
+    if message_block_height > commit_block_height {
+        Err(anyhow::anyhow!(
+            "The `message_block_height` is higher than `commit_block_height`"
+        ))?;
+    }
+    Ok(())
+}
+
 #[allow(clippy::arithmetic_side_effects)]
 #[cfg(test)]
 mod tests {
@@ -229,4 +323,90 @@ mod tests {
         );
         assert!(result.is_err());
     }
+
+    #[test]
+    fn block_history_proofs_match_single_proofs_and_keep_order() {
+        let mut database = Database::default();
+
+        insert_test_ascending_blocks(&mut database, BlockHeight::from(0));
+
+        let requests = [
+            (BlockHeight::from(3), BlockHeight::from(7)),
+            (BlockHeight::from(TEST_BLOCKS_COUNT), BlockHeight::from(1)),
+            (BlockHeight::from(0), BlockHeight::from(7)),
+            (
+                BlockHeight::from(5),
+                BlockHeight::from(TEST_BLOCKS_COUNT + 1),
+            ),
+            (BlockHeight::from(2), BlockHeight::from(4)),
+        ];
+
+        let proofs = database.block_history_proofs(&requests).collect::<Vec<_>>();
+
+        assert_eq!(proofs.len(), requests.len());
+        for ((message, commit), proof) in requests.iter().zip(proofs) {
+            match database.block_history_proof(message, commit) {
+                Ok(expected) => assert_eq!(proof.unwrap(), expected),
+                Err(_) => assert!(proof.is_err()),
+            }
+        }
+    }
+
+    #[test]
+    fn block_history_proofs_for_commit_match_single_proofs() {
+        let mut database = Database::default();
+
+        insert_test_ascending_blocks(&mut database, BlockHeight::from(0));
+
+        let commit = BlockHeight::from(7);
+        let messages = [
+            BlockHeight::from(3),
+            BlockHeight::from(0),
+            BlockHeight::from(7),
+        ];
+
+        let proofs = database
+            .block_history_proofs_for_commit(&commit, &messages)
+            .expect("Should return the merkle proofs");
+
+        assert_eq!(proofs.len(), messages.len());
+        for (message, proof) in messages.iter().zip(proofs) {
+            let expected = database.block_history_proof(message, &commit).unwrap();
+            assert_eq!(proof, expected);
+        }
+        assert!(database
+            .block_history_proofs_for_commit(&commit, &[BlockHeight::from(8)])
+            .is_err());
+    }
+
+    #[test_case(0; "genesis block at height 0")]
+    #[test_case(100; "genesis block at height 100")]
+    fn block_history_proofs_are_verified_against_block_history_root(genesis_height: u32) {
+        let mut database = Database::default();
+
+        insert_test_ascending_blocks(&mut database, BlockHeight::from(genesis_height));
+
+        let commit = BlockHeight::from(genesis_height + 7);
+        let root = database
+            .block_history_root(&commit)
+            .expect("Should return the root");
+        for l in 0..=7 {
+            let message = BlockHeight::from(genesis_height + l);
+            let proof = database.block_history_proof(&message, &commit).unwrap();
+            let block_id: Bytes32 = database
+                .storage::<FuelBlocks>()
+                .get(&message)
+                .unwrap()
+                .unwrap()
+                .id()
+                .into();
+
+            assert!(crate::query::verify_block_history_proof(
+                &proof, &root, &block_id, 8
+            ));
+        }
+        assert!(database
+            .block_history_root(&BlockHeight::from(genesis_height + TEST_BLOCKS_COUNT))
+            .is_err());
+    }
 }
@@ -3,9 +3,11 @@ use fuel_core_chain_config::TableEntry;
 use fuel_core_storage::{
     iter::{
         IterDirection,
+        IterableStore,
         IteratorOverTable,
     },
     not_found,
+    structured_storage::TableWithBlueprint,
     tables::{
         ContractsAssets,
         ContractsLatestUtxo,
@@ -13,11 +15,14 @@ use fuel_core_storage::{
         ContractsState,
     },
     ContractsAssetKey,
+    ContractsStateKey,
+    Error as StorageError,
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_types::fuel_types::{
     AssetId,
+    Bytes32,
     ContractId,
 };
 use itertools::Itertools;
@@ -92,6 +97,65 @@ impl Database {
         )
         .map_ok(|(key, value)| TableEntry { key, value })
     }
+
+    /// Counts the balances of the contract. Only the raw keys under the contract
+    /// prefix are scanned, the values are not decoded.
+    pub fn contract_balance_count(&self, contract: ContractId) -> StorageResult<u64> {
+        self.iter_store(
+            ContractsAssets::column(),
+            Some(contract.as_ref()),
+            None,
+            IterDirection::Forward,
+        )
+        .try_fold(0u64, |count, item| {
+            item?;
+            Ok(count.saturating_add(1))
+        })
+    }
+
+    /// Returns the balance of the `asset_id` owned by the `contract`,
+    /// or `None` if the contract doesn't own the asset.
+    pub fn contract_asset_balance(
+        &self,
+        contract: ContractId,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<u64>> {
+        let balance = self
+            .storage::<ContractsAssets>()
+            .get(&ContractsAssetKey::new(&contract, asset_id))?
+            .map(|balance| *balance);
+        Ok(balance)
+    }
+
+    /// Returns the value of the contract's storage slot at the `key`,
+    /// or `None` if the slot is not set.
+    pub fn contract_state(
+        &self,
+        contract: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        let Some(value) = self
+            .storage::<ContractsState>()
+            .get(&ContractsStateKey::new(&contract, &key))?
+        else {
+            return Ok(None)
+        };
+
+        let value = Bytes32::try_from(value.0.as_slice()).map_err(|_| {
+            StorageError::Other(anyhow::anyhow!(
+                "The storage slot {key} of the contract {contract} has {} bytes instead of 32",
+                value.0.len()
+            ))
+        })?;
+        Ok(Some(value))
+    }
+
+    /// Returns the root of the sparse Merkle tree over the storage slots of the
+    /// `contract`. The root of the empty tree is returned if no slot is set.
+    pub fn contract_state_root(&self, contract: &ContractId) -> StorageResult<Bytes32> {
+        let root = self.storage::<ContractsState>().root(contract)?;
+        Ok(root.into())
+    }
 }
 
 #[cfg(test)]
@@ -99,7 +163,13 @@ mod tests {
     use super::*;
     use crate::database::database_description::on_chain::OnChain;
     use fuel_core_storage::StorageAsMut;
-    use fuel_core_types::fuel_tx::Contract;
+    use fuel_core_types::{
+        fuel_merkle::sparse::{
+            in_memory::MerkleTree,
+            MerkleTreeKey,
+        },
+        fuel_tx::Contract,
+    };
     use rand::{
         RngCore,
         SeedableRng,
@@ -127,4 +197,120 @@ mod tests {
             .into_owned();
         assert_eq!(returned, contract);
     }
+
+    #[test]
+    fn contract_balance_count_counts_only_balances_of_the_contract() {
+        // Given
+        let contract_id = ContractId::from([1u8; 32]);
+        let other_contract_id = ContractId::from([2u8; 32]);
+        let database = &mut Database::<OnChain>::default();
+        for asset in 0..3u8 {
+            let asset_id = AssetId::from([asset; 32]);
+            database
+                .storage::<ContractsAssets>()
+                .insert(&(&contract_id, &asset_id).into(), &100)
+                .unwrap();
+        }
+        database
+            .storage::<ContractsAssets>()
+            .insert(&(&other_contract_id, &AssetId::zeroed()).into(), &100)
+            .unwrap();
+
+        // When
+        let count = database.contract_balance_count(contract_id).unwrap();
+
+        // Then
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn contract_asset_balance_returns_the_balance_of_the_asset() {
+        // Given
+        let contract_id = ContractId::from([1u8; 32]);
+        let asset_id = AssetId::from([2u8; 32]);
+        let database = &mut Database::<OnChain>::default();
+        database
+            .storage::<ContractsAssets>()
+            .insert(&(&contract_id, &asset_id).into(), &100)
+            .unwrap();
+
+        // When
+        let balance = database
+            .contract_asset_balance(contract_id, &asset_id)
+            .unwrap();
+        let missing = database
+            .contract_asset_balance(contract_id, &AssetId::from([3u8; 32]))
+            .unwrap();
+
+        // Then
+        assert_eq!(balance, Some(100));
+        assert_eq!(missing, None);
+    }
+
+    #[test]
+    fn contract_state_returns_the_value_of_the_slot() {
+        // Given
+        let contract_id = ContractId::from([1u8; 32]);
+        let key = Bytes32::from([2u8; 32]);
+        let value = Bytes32::from([3u8; 32]);
+        let database = &mut Database::<OnChain>::default();
+        database
+            .storage::<ContractsState>()
+            .insert(&ContractsStateKey::new(&contract_id, &key), value.as_ref())
+            .unwrap();
+
+        // When
+        let stored = database.contract_state(contract_id, key).unwrap();
+        let missing = database
+            .contract_state(contract_id, Bytes32::from([4u8; 32]))
+            .unwrap();
+
+        // Then
+        assert_eq!(stored, Some(value));
+        assert_eq!(missing, None);
+    }
+
+    #[test]
+    fn contract_state_root_covers_only_slots_of_the_contract() {
+        // Given
+        let contract_id = ContractId::from([1u8; 32]);
+        let other_contract_id = ContractId::from([2u8; 32]);
+        let database = &mut Database::<OnChain>::default();
+        let empty_root = database.contract_state_root(&contract_id).unwrap();
+        let slots = [
+            (
+                ContractsStateKey::new(&contract_id, &[1u8; 32].into()),
+                [1u8; 32],
+            ),
+            (
+                ContractsStateKey::new(&contract_id, &[2u8; 32].into()),
+                [2u8; 32],
+            ),
+        ];
+        for (key, value) in &slots {
+            database
+                .storage::<ContractsState>()
+                .insert(key, value.as_slice())
+                .unwrap();
+        }
+        database
+            .storage::<ContractsState>()
+            .insert(
+                &ContractsStateKey::new(&other_contract_id, &[3u8; 32].into()),
+                [3u8; 32].as_slice(),
+            )
+            .unwrap();
+
+        // When
+        let root = database.contract_state_root(&contract_id).unwrap();
+
+        // Then
+        let expected_root = MerkleTree::root_from_set(
+            slots
+                .iter()
+                .map(|(key, value)| (MerkleTreeKey::new(*key), value)),
+        );
+        assert_eq!(empty_root, Bytes32::from(MerkleTree::new().root()));
+        assert_eq!(root, Bytes32::from(expected_root));
+    }
 }
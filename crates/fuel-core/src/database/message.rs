@@ -12,8 +12,10 @@ use fuel_core_chain_config::TableEntry;
 use fuel_core_storage::{
     iter::{
         IterDirection,
+        IterableStore,
         IteratorOverTable,
     },
+    structured_storage::TableWithBlueprint,
     tables::{
         Messages,
         SpentMessages,
@@ -22,7 +24,10 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use fuel_core_types::{
-    entities::relayer::message::Message,
+    entities::relayer::message::{
+        Message,
+        MessageStatus,
+    },
     fuel_types::{
         Address,
         Nonce,
@@ -46,18 +51,65 @@ impl Database<OffChain> {
         )
         .map(|res| res.map(|(key, _)| *key.nonce()))
     }
+
+    /// Counts the messages of the `owner`. Only the raw keys under the owner
+    /// prefix are scanned, the values are not decoded.
+    pub fn owned_message_count(&self, owner: &Address) -> StorageResult<u64> {
+        self.iter_store(
+            OwnedMessageIds::column(),
+            Some(owner.as_ref()),
+            None,
+            IterDirection::Forward,
+        )
+        .try_fold(0u64, |count, item| {
+            item?;
+            Ok(count.saturating_add(1))
+        })
+    }
 }
 
 impl Database {
+    /// Returns the messages ordered by the nonce.
+    ///
+    /// The `start` cursor is inclusive for the `IterDirection::Forward`. For the
+    /// `IterDirection::Reverse` it is exclusive: the iteration yields only messages
+    /// with nonces strictly less than the cursor.
     pub fn all_messages(
         &self,
         start: Option<Nonce>,
         direction: Option<IterDirection>,
     ) -> impl Iterator<Item = StorageResult<Message>> + '_ {
+        // The storage iterator includes the `start` key in both directions,
+        // while in the reverse direction the cursor is exclusive.
+        let excluded = match direction.unwrap_or_default() {
+            IterDirection::Forward => None,
+            IterDirection::Reverse => start,
+        };
         self.iter_all_by_start::<Messages>(start.as_ref(), direction)
+            .skip_while(move |result| {
+                matches!(result, Ok((nonce, _)) if Some(*nonce) == excluded)
+            })
             .map(|res| res.map(|(_, message)| message))
     }
 
+    /// Returns the messages that are not spent yet. The order is the same
+    /// as in the [`Self::all_messages`] with spent messages skipped.
+    pub fn unspent_messages(
+        &self,
+        start: Option<Nonce>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<Message>> + '_ {
+        self.all_messages(start, direction)
+            .filter_map(|result| match result {
+                Ok(message) => match self.message_is_spent(message.id()) {
+                    Ok(false) => Some(Ok(message)),
+                    Ok(true) => None,
+                    Err(e) => Some(Err(e)),
+                },
+                Err(e) => Some(Err(e)),
+            })
+    }
+
     pub fn iter_messages(
         &self,
     ) -> impl Iterator<Item = StorageResult<TableEntry<Messages>>> + '_ {
@@ -84,4 +136,197 @@ impl Database {
     pub fn message_exists(&self, id: &Nonce) -> StorageResult<bool> {
         fuel_core_storage::StorageAsRef::storage::<Messages>(&self).contains_key(id)
     }
+
+    /// Returns the state of the message in the lifecycle.
+    ///
+    /// Spent messages are removed from the [`Messages`], so the [`SpentMessages`]
+    /// is checked first. Both tables are read from the same view of the database.
+    pub fn message_status(&self, id: &Nonce) -> StorageResult<MessageStatus> {
+        if self.message_is_spent(id)? {
+            Ok(MessageStatus::spent())
+        } else if self.message_exists(id)? {
+            Ok(MessageStatus::unspent())
+        } else {
+            Ok(MessageStatus::not_found())
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageAsMut;
+    use fuel_core_types::entities::relayer::message::MessageState;
+
+    fn message(nonce: u8) -> Message {
+        let mut message = Message::default();
+        message.set_nonce([nonce; 32].into());
+        message
+    }
+
+    #[test]
+    fn unspent_messages_skips_spent_messages_in_order() {
+        // Given
+        let mut database = Database::default();
+        for nonce in 0..5 {
+            let message = message(nonce);
+            database
+                .storage_as_mut::<Messages>()
+                .insert(message.id(), &message)
+                .unwrap();
+        }
+        for nonce in [1, 3] {
+            database
+                .storage_as_mut::<SpentMessages>()
+                .insert(message(nonce).id(), &())
+                .unwrap();
+        }
+
+        // When
+        let forward = database
+            .unspent_messages(None, Some(IterDirection::Forward))
+            .map_ok(|message| *message.nonce())
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+        let reverse = database
+            .unspent_messages(None, Some(IterDirection::Reverse))
+            .map_ok(|message| *message.nonce())
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        let expected: Vec<Nonce> = [0u8, 2, 4].map(|nonce| [nonce; 32].into()).to_vec();
+        assert_eq!(forward, expected);
+        assert_eq!(reverse, expected.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    fn nonces(
+        database: &Database,
+        start: Option<u8>,
+        direction: IterDirection,
+    ) -> Vec<Nonce> {
+        database
+            .all_messages(start.map(|nonce| [nonce; 32].into()), Some(direction))
+            .map_ok(|message| *message.nonce())
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap()
+    }
+
+    fn database_with_messages(nonces: &[u8]) -> Database {
+        let mut database = Database::default();
+        for nonce in nonces {
+            let message = message(*nonce);
+            database
+                .storage_as_mut::<Messages>()
+                .insert(message.id(), &message)
+                .unwrap();
+        }
+        database
+    }
+
+    #[test]
+    fn all_messages__empty_database_yields_nothing_in_both_directions() {
+        // Given
+        let database = Database::default();
+
+        // When
+        let forward = nonces(&database, Some(1), IterDirection::Forward);
+        let reverse = nonces(&database, Some(1), IterDirection::Reverse);
+
+        // Then
+        assert!(forward.is_empty());
+        assert!(reverse.is_empty());
+    }
+
+    #[test]
+    fn all_messages__single_message_cursor_is_inclusive_only_forward() {
+        // Given
+        let database = database_with_messages(&[1]);
+
+        // When
+        let forward = nonces(&database, Some(1), IterDirection::Forward);
+        let reverse = nonces(&database, Some(1), IterDirection::Reverse);
+        let reverse_all = nonces(&database, None, IterDirection::Reverse);
+
+        // Then
+        assert_eq!(forward, vec![[1; 32].into()]);
+        assert!(reverse.is_empty());
+        assert_eq!(reverse_all, vec![[1; 32].into()]);
+    }
+
+    #[test]
+    fn all_messages__cursor_on_stored_nonce_in_both_directions() {
+        // Given
+        let database = database_with_messages(&[1, 3, 5]);
+        let expected = |nonces: &[u8]| -> Vec<Nonce> {
+            nonces.iter().map(|nonce| [*nonce; 32].into()).collect()
+        };
+
+        // When
+        let forward = nonces(&database, Some(3), IterDirection::Forward);
+        let reverse = nonces(&database, Some(3), IterDirection::Reverse);
+        let reverse_from_missing = nonces(&database, Some(4), IterDirection::Reverse);
+
+        // Then
+        assert_eq!(forward, expected(&[3, 5]));
+        assert_eq!(reverse, expected(&[1]));
+        assert_eq!(reverse_from_missing, expected(&[3, 1]));
+    }
+
+    #[test]
+    fn message_status_distinguishes_spent_and_unknown_messages() {
+        // Given
+        let mut database = Database::default();
+        let unspent = message(1);
+        database
+            .storage_as_mut::<Messages>()
+            .insert(unspent.id(), &unspent)
+            .unwrap();
+        let spent = message(2);
+        database
+            .storage_as_mut::<SpentMessages>()
+            .insert(spent.id(), &())
+            .unwrap();
+
+        // When
+        let unspent_status = database.message_status(unspent.id()).unwrap();
+        let spent_status = database.message_status(spent.id()).unwrap();
+        let unknown_status = database.message_status(message(3).id()).unwrap();
+
+        // Then
+        assert!(matches!(unspent_status.state, MessageState::Unspent));
+        assert!(matches!(spent_status.state, MessageState::Spent));
+        assert!(matches!(unknown_status.state, MessageState::NotFound));
+    }
+
+    #[test]
+    fn owned_message_count_counts_only_messages_of_the_owner() {
+        // Given
+        let mut database = Database::<OffChain>::default();
+        let owner = Address::from([1; 32]);
+        let other_owner = Address::from([2; 32]);
+        for nonce in 0..3 {
+            database
+                .storage_as_mut::<OwnedMessageIds>()
+                .insert(&OwnedMessageKey::new(&owner, &[nonce; 32].into()), &())
+                .unwrap();
+        }
+        database
+            .storage_as_mut::<OwnedMessageIds>()
+            .insert(&OwnedMessageKey::new(&other_owner, &[0; 32].into()), &())
+            .unwrap();
+
+        // When
+        let count = database.owned_message_count(&owner).unwrap();
+
+        // Then
+        assert_eq!(count, 3);
+        assert_eq!(
+            database
+                .owned_message_count(&Address::from([3; 32]))
+                .unwrap(),
+            0
+        );
+    }
 }
@@ -4,8 +4,11 @@ use fuel_core_storage::{
 };
 use std::net::SocketAddr;
 
+use self::database::ReadViewConfig;
+
 pub mod api_service;
 pub mod database;
+pub(crate) mod message_proof_cache;
 pub(crate) mod metrics_extension;
 pub mod ports;
 pub mod storage;
@@ -21,6 +24,7 @@ pub struct Config {
     pub max_tx: usize,
     pub max_depth: usize,
     pub chain_name: String,
+    pub read_view: ReadViewConfig,
 }
 
 pub trait IntoApiResult<T> {
@@ -40,6 +40,7 @@ use axum::{
             ACCESS_CONTROL_ALLOW_ORIGIN,
         },
         HeaderValue,
+        StatusCode,
     },
     response::{
         sse::Event,
@@ -59,7 +60,10 @@ use fuel_core_services::{
     RunnableTask,
     StateWatcher,
 };
-use fuel_core_storage::transactional::AtomicView;
+use fuel_core_storage::{
+    transactional::AtomicView,
+    Error as StorageError,
+};
 use fuel_core_types::fuel_types::BlockHeight;
 use futures::Stream;
 use serde_json::json;
@@ -70,6 +74,7 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    sync::Arc,
     time::Duration,
 };
 use tokio_stream::StreamExt;
@@ -191,11 +196,15 @@ where
     OffChain::View: OffChainDatabase,
 {
     let network_addr = config.addr;
-    let combined_read_database = ReadDatabase::new(on_database, off_database);
+    let combined_read_database = Arc::new(ReadDatabase::new(
+        on_database,
+        off_database,
+        config.read_view,
+    ));
 
     let schema = schema
         .data(config)
-        .data(combined_read_database)
+        .data(combined_read_database.clone())
         .data(txpool)
         .data(producer)
         .data(consensus_module)
@@ -217,7 +226,10 @@ where
         .route("/v1/metrics", get(metrics))
         .route("/v1/health", get(health))
         .route("/health", get(health))
+        .route("/v1/readiness", get(readiness))
+        .route("/readiness", get(readiness))
         .layer(Extension(schema))
+        .layer(Extension(combined_read_database))
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(request_timeout))
         .layer(SetResponseHeaderLayer::<_>::overriding(
@@ -255,6 +267,26 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+/// Reports whether the node can serve queries. Unlike the `/health` liveness check,
+/// it fails with `503 Service Unavailable` until both databases respond and the
+/// off-chain worker has indexed the genesis block, or if the heights of the
+/// databases drift apart by more than the configured skew.
+async fn readiness(
+    Extension(database): Extension<Arc<ReadDatabase>>,
+) -> impl IntoResponse {
+    let check = tokio::task::spawn_blocking(move || database.readiness_check()).await;
+    match check.map_err(|err| StorageError::Other(err.into())) {
+        Ok(Ok(())) => (StatusCode::OK, Json(json!({ "ready": true }))),
+        Ok(Err(err)) | Err(err) => {
+            tracing::warn!("The database is not ready to serve queries: {err}");
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(json!({ "ready": false })),
+            )
+        }
+    }
+}
+
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
     req: Json<Request>,
@@ -1,8 +1,10 @@
 use crate::fuel_core_graphql_api::{
     database::arc_wrapper::ArcWrapper,
+    message_proof_cache::MessageProofCache,
     ports::{
         DatabaseBlocks,
         DatabaseChain,
+        DatabaseCoins,
         DatabaseContracts,
         DatabaseMessageProof,
         DatabaseMessages,
@@ -10,6 +12,7 @@ use crate::fuel_core_graphql_api::{
         OffChainDatabase,
         OnChainDatabase,
     },
+    storage::transactions::TransactionStatuses,
 };
 use fuel_core_storage::{
     iter::{
@@ -17,10 +20,19 @@ use fuel_core_storage::{
         IntoBoxedIter,
         IterDirection,
     },
+    not_found,
+    tables::{
+        Coins,
+        FuelBlocks,
+        Messages,
+        SealedBlockConsensus,
+        Transactions,
+    },
     transactional::AtomicView,
     Error as StorageError,
     Mappable,
     Result as StorageResult,
+    StorageAsRef,
     StorageInspect,
 };
 use fuel_core_txpool::types::{
@@ -36,18 +48,29 @@ use fuel_core_types::{
             DaBlockHeight,
         },
     },
-    entities::relayer::{
-        message::{
-            MerkleProof,
-            Message,
+    entities::{
+        coins::coin::{
+            Coin,
+            CompressedCoin,
+        },
+        relayer::{
+            message::{
+                MerkleProof,
+                Message,
+                MessageStatus,
+            },
+            transaction::RelayedTransactionStatus,
         },
-        transaction::RelayedTransactionStatus,
     },
     fuel_tx::{
+        field::Outputs,
         Address,
         AssetId,
         Bytes32,
+        Output,
+        Receipt,
         Salt,
+        Transaction,
         TxPointer,
         UtxoId,
     },
@@ -60,9 +83,25 @@ use fuel_core_types::{
         txpool::TransactionStatus,
     },
 };
+use futures::{
+    StreamExt,
+    TryStreamExt,
+};
+use itertools::Itertools;
 use std::{
     borrow::Cow,
-    sync::Arc,
+    collections::{
+        BTreeSet,
+        HashSet,
+    },
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+        OnceLock,
+    },
 };
 
 mod arc_wrapper;
@@ -71,19 +110,123 @@ mod arc_wrapper;
 pub type OnChainView = Arc<dyn OnChainDatabase>;
 /// The off-chain view of the database used by the [`ReadView`] to fetch off-chain data.
 pub type OffChainView = Arc<dyn OffChainDatabase>;
+/// The on-chain and off-chain views created from the same snapshot of the database.
+pub type SharedView = (OnChainView, OffChainView);
+
+/// The configuration of the [`ReadView`]s created by the [`ReadDatabase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadViewConfig {
+    /// The number of times a read that failed with an error of the underlying datastore
+    /// is retried. The reads are not retried if it is zero.
+    ///
+    /// The retries are immediate, because the reads run synchronously on the threads
+    /// of the async runtime and waiting would block them.
+    pub max_retries: usize,
+    /// The maximum size of the message data in bytes for which the message proof
+    /// is generated. The size is not limited if it is `None`.
+    pub max_message_data_size: Option<usize>,
+    /// Logs a warning on every read if the on-chain and off-chain heights of the view
+    /// diverge, or if the off-chain database returns data from above the pinned height.
+    /// Meant for debugging the inconsistency of the databases, disabled by default.
+    pub check_consistency: bool,
+    /// The maximum difference between the latest on-chain and off-chain heights
+    /// for which [`ReadDatabase::view`] creates the view. The off-chain database
+    /// is updated after the on-chain one, so it usually lags by at most one block.
+    pub max_height_skew: u32,
+    /// The maximum number of commit block heights for which the message proofs
+    /// are cached and shared between the views. Nothing is cached if it is zero.
+    pub message_proof_cache_size: usize,
+    /// The maximum number of the cached message proofs per commit block height.
+    /// Nothing is cached if it is zero.
+    pub message_proofs_per_commit: usize,
+    /// The number of blocks below the latest height for which the cached message
+    /// proofs are kept. The proofs against older commit blocks are evicted.
+    pub message_proof_cache_horizon: u32,
+}
+
+impl Default for ReadViewConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            max_message_data_size: None,
+            check_consistency: false,
+            max_height_skew: 1,
+            message_proof_cache_size: 32,
+            message_proofs_per_commit: 256,
+            message_proof_cache_horizon: 1024,
+        }
+    }
+}
+
+impl ReadViewConfig {
+    fn retries(&self) -> Retries {
+        Retries {
+            left: self.max_retries,
+        }
+    }
+
+    /// Runs the `read`, retrying it immediately after the transient errors
+    /// of the underlying datastore.
+    fn retry<T>(&self, mut read: impl FnMut() -> StorageResult<T>) -> StorageResult<T> {
+        let mut retries = self.retries();
+        loop {
+            match read() {
+                Err(err) if retries.retry(&err) => continue,
+                result => return result,
+            }
+        }
+    }
+}
+
+/// The retries left for one read of the [`ReadView`].
+struct Retries {
+    left: usize,
+}
+
+impl Retries {
+    /// Returns `true` if the `error` is transient and retries are left,
+    /// or `false` if the `error` should be returned to the caller instead.
+    fn retry(&mut self, error: &StorageError) -> bool {
+        if self.left == 0 || !matches!(error, StorageError::DatabaseError(_)) {
+            return false
+        }
+        tracing::debug!("Retrying the read after the storage error: {error:?}");
+        self.left = self.left.saturating_sub(1);
+        true
+    }
+}
+
+/// The providers of the on-chain and off-chain views of the [`ReadDatabase`].
+enum ViewProviders {
+    /// The on-chain and off-chain views are snapshotted independently.
+    Split {
+        /// The on-chain database view provider.
+        on_chain: Box<dyn AtomicView<View = OnChainView, Height = BlockHeight>>,
+        /// The off-chain database view provider.
+        off_chain: Box<dyn AtomicView<View = OffChainView, Height = BlockHeight>>,
+    },
+    /// Both views are created from one snapshot of the same physical database.
+    Shared(Box<dyn AtomicView<View = SharedView, Height = BlockHeight>>),
+}
 
 /// The container of the on-chain and off-chain database view provides.
 /// It is used only by `ViewExtension` to create a [`ReadView`].
 pub struct ReadDatabase {
-    /// The on-chain database view provider.
-    on_chain: Box<dyn AtomicView<View = OnChainView, Height = BlockHeight>>,
-    /// The off-chain database view provider.
-    off_chain: Box<dyn AtomicView<View = OffChainView, Height = BlockHeight>>,
+    /// The providers of the database views.
+    providers: ViewProviders,
+    /// The configuration of the created views.
+    config: ReadViewConfig,
+    /// The message proofs shared by the created views.
+    message_proofs: Arc<MessageProofCache>,
 }
 
 impl ReadDatabase {
     /// Creates a new [`ReadDatabase`] with the given on-chain and off-chain database view providers.
-    pub fn new<OnChain, OffChain>(on_chain: OnChain, off_chain: OffChain) -> Self
+    pub fn new<OnChain, OffChain>(
+        on_chain: OnChain,
+        off_chain: OffChain,
+        config: ReadViewConfig,
+    ) -> Self
     where
         OnChain: AtomicView<Height = BlockHeight> + 'static,
         OffChain: AtomicView<Height = BlockHeight> + 'static,
@@ -91,226 +234,3851 @@ impl ReadDatabase {
         OffChain::View: OffChainDatabase,
     {
         Self {
-            on_chain: Box::new(ArcWrapper::new(on_chain)),
-            off_chain: Box::new(ArcWrapper::new(off_chain)),
+            providers: ViewProviders::Split {
+                on_chain: Box::new(ArcWrapper::new(on_chain)),
+                off_chain: Box::new(ArcWrapper::new(off_chain)),
+            },
+            config,
+            message_proofs: Arc::new(MessageProofCache::new(
+                config.message_proof_cache_size,
+                config.message_proofs_per_commit,
+                config.message_proof_cache_horizon,
+            )),
         }
     }
 
-    /// Creates a consistent view of the database.
-    pub fn view(&self) -> ReadView {
-        // TODO: Use the same height for both views to guarantee consistency.
-        //  It is not possible to implement until `view_at` is implemented for the `AtomicView`.
-        //  https://github.com/FuelLabs/fuel-core/issues/1582
-        ReadView {
-            on_chain: self.on_chain.latest_view(),
-            off_chain: self.off_chain.latest_view(),
+    /// Creates a new [`ReadDatabase`] with a single provider of both views, for the case
+    /// when the on-chain and off-chain databases are backed by the same physical database.
+    ///
+    /// Each [`ReadView`] takes one snapshot instead of two, and both of its views always
+    /// have the same height.
+    pub fn new_shared<Shared, OnChain, OffChain>(
+        db: Shared,
+        config: ReadViewConfig,
+    ) -> Self
+    where
+        Shared: AtomicView<View = (OnChain, OffChain), Height = BlockHeight> + 'static,
+        OnChain: OnChainDatabase + 'static,
+        OffChain: OffChainDatabase + 'static,
+    {
+        Self {
+            providers: ViewProviders::Shared(Box::new(ArcWrapper::new(db))),
+            config,
+            message_proofs: Arc::new(MessageProofCache::new(
+                config.message_proof_cache_size,
+                config.message_proofs_per_commit,
+                config.message_proof_cache_horizon,
+            )),
         }
     }
-}
-
-pub struct ReadView {
-    on_chain: OnChainView,
-    off_chain: OffChainView,
-}
-
-impl DatabaseBlocks for ReadView {
-    fn blocks(
-        &self,
-        height: Option<BlockHeight>,
-        direction: IterDirection,
-    ) -> BoxedIter<'_, StorageResult<CompressedBlock>> {
-        // Chain together blocks from the off-chain db and the on-chain db
-        // The blocks in off-chain db, if any, are from time before regenesis
 
-        if let Some(height) = height {
-            match self.on_chain.latest_genesis_height() {
-                Ok(onchain_start_height) => {
-                    match (height >= onchain_start_height, direction) {
-                        (true, IterDirection::Forward) => {
-                            self.on_chain.blocks(Some(height), direction)
-                        }
-                        (true, IterDirection::Reverse) => self
-                            .on_chain
-                            .blocks(Some(height), direction)
-                            .chain(self.off_chain.old_blocks(None, direction))
-                            .into_boxed(),
-                        (false, IterDirection::Forward) => self
-                            .off_chain
-                            .old_blocks(Some(height), direction)
-                            .chain(self.on_chain.blocks(None, direction))
-                            .into_boxed(),
-                        (false, IterDirection::Reverse) => {
-                            self.off_chain.old_blocks(Some(height), direction)
-                        }
+    /// Creates a consistent view of the database.
+    ///
+    /// The latest height is resolved once for both providers, and both views
+    /// are pinned at the lowest of them. If the heights differ by more than
+    /// the [`ReadViewConfig::max_height_skew`], [`ReadViewError::ViewSkewExceeded`]
+    /// is returned instead.
+    pub fn view(&self) -> StorageResult<ReadView> {
+        let heights = self.latest_heights();
+        let height = match heights {
+            (Some(on_chain), Some(off_chain)) => {
+                let skew = u32::from(on_chain).abs_diff(u32::from(off_chain));
+                if skew > self.config.max_height_skew {
+                    return Err(ReadViewError::ViewSkewExceeded {
+                        on_chain,
+                        off_chain,
+                        max_skew: self.config.max_height_skew,
                     }
+                    .into())
                 }
-                Err(err) => core::iter::once(Err(err)).into_boxed(),
+                on_chain.min(off_chain)
             }
-        } else {
-            match direction {
-                IterDirection::Forward => self
-                    .off_chain
-                    .old_blocks(None, direction)
-                    .chain(self.on_chain.blocks(None, direction))
-                    .into_boxed(),
-                IterDirection::Reverse => self
-                    .on_chain
-                    .blocks(None, direction)
-                    .chain(self.off_chain.old_blocks(None, direction))
-                    .into_boxed(),
+            (Some(height), None) | (None, Some(height)) => height,
+            (None, None) => return Err(not_found!("BlockHeight")),
+        };
+        self.view_with_heights(height, heights)
+    }
+
+    /// Creates a view like [`Self::view`], retrying it after the transient errors
+    /// of the underlying datastore. Unlike the reads of the [`ReadView`], it yields
+    /// to the async runtime between the attempts.
+    pub async fn view_with_retries(&self) -> StorageResult<ReadView> {
+        let mut retries = self.config.retries();
+        loop {
+            match self.view() {
+                Err(err) if retries.retry(&err) => tokio::task::yield_now().await,
+                view => return view,
             }
         }
     }
 
-    fn latest_height(&self) -> StorageResult<BlockHeight> {
-        self.on_chain.latest_height()
+    /// Creates a view of the database, re-snapshotting it up to `max_retries` times
+    /// until the on-chain and off-chain databases report the same latest height.
+    ///
+    /// If the heights don't converge, the last view is returned as is,
+    /// and its [`ReadView::is_consistent`] is `false`. Like [`Self::view`], it fails
+    /// if the heights differ by more than the [`ReadViewConfig::max_height_skew`].
+    pub fn consistent_view(&self, max_retries: usize) -> StorageResult<ReadView> {
+        let mut view = self.view()?;
+        for _ in 0..max_retries {
+            if view.is_consistent() {
+                break
+            }
+            std::thread::yield_now();
+            view = self.view()?;
+        }
+        Ok(view)
     }
 
-    fn latest_genesis_height(&self) -> StorageResult<BlockHeight> {
-        self.on_chain.latest_genesis_height()
+    /// Creates a view of the database where both on-chain and off-chain views
+    /// are pinned at the same `height`.
+    pub fn view_at(&self, height: BlockHeight) -> StorageResult<ReadView> {
+        self.view_with_heights(height, self.latest_heights())
     }
-}
 
-impl<M> StorageInspect<M> for ReadView
-where
-    M: Mappable,
-    dyn OnChainDatabase: StorageInspect<M, Error = StorageError>,
-{
-    type Error = StorageError;
+    /// Opens a view and performs a trivial read against both databases,
+    /// to confirm that both backing stores respond.
+    ///
+    /// The on-chain database is asked for its latest height, and the off-chain
+    /// database for the height of the on-chain genesis block.
+    ///
+    /// The reads are synchronous, so async callers should run the check on
+    /// the blocking thread pool.
+    pub fn readiness_check(&self) -> StorageResult<()> {
+        let view = self.view()?;
+        view.on_chain.latest_height()?;
+        let genesis_height = view.on_chain.latest_genesis_height()?;
+        let genesis_block = view
+            .on_chain
+            .blocks(Some(genesis_height), IterDirection::Forward)
+            .next()
+            .ok_or(not_found!(FuelBlocks))??;
+        view.off_chain.block_height(&genesis_block.header().id())?;
+        Ok(())
+    }
 
-    fn get(&self, key: &M::Key) -> StorageResult<Option<Cow<M::OwnedValue>>> {
-        self.on_chain.get(key)
+    fn view_with_heights(
+        &self,
+        height: BlockHeight,
+        (on_chain_height, off_chain_height): (Option<BlockHeight>, Option<BlockHeight>),
+    ) -> StorageResult<ReadView> {
+        let (on_chain, off_chain) = match &self.providers {
+            ViewProviders::Split {
+                on_chain,
+                off_chain,
+            } => (on_chain.view_at(&height)?, off_chain.view_at(&height)?),
+            ViewProviders::Shared(shared) => shared.view_at(&height)?,
+        };
+        Ok(ReadView {
+            height,
+            on_chain_height,
+            off_chain_height,
+            genesis_height: OnceLock::new(),
+            regenesis_checked: OnceLock::new(),
+            config: self.config,
+            message_proofs: self.message_proofs.clone(),
+            on_chain,
+            off_chain,
+        })
     }
 
-    fn contains_key(&self, key: &M::Key) -> StorageResult<bool> {
-        self.on_chain.contains_key(key)
+    /// Returns the latest heights of the on-chain and off-chain databases.
+    ///
+    /// The off-chain database is updated after the on-chain database, so it can lag
+    /// behind. If the off-chain database hasn't processed any block yet,
+    /// its height is `None`. The shared provider reports the same height for both.
+    fn latest_heights(&self) -> (Option<BlockHeight>, Option<BlockHeight>) {
+        match &self.providers {
+            ViewProviders::Split {
+                on_chain,
+                off_chain,
+            } => (on_chain.latest_height(), off_chain.latest_height()),
+            ViewProviders::Shared(shared) => {
+                let height = shared.latest_height();
+                (height, height)
+            }
+        }
     }
 }
 
-impl DatabaseMessages for ReadView {
-    fn all_messages(
-        &self,
-        start_message_id: Option<Nonce>,
-        direction: IterDirection,
-    ) -> BoxedIter<'_, StorageResult<Message>> {
-        self.on_chain.all_messages(start_message_id, direction)
-    }
+/// The state of the coin returned by the [`ReadView::coin_with_status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoinStatus {
+    /// The coin exists and can be spent.
+    Unspent(CompressedCoin),
+    /// The coin was created by a known transaction and spent since then.
+    Spent,
+    /// The coin never existed, or its creation is unknown to the view.
+    Unknown,
+}
 
-    fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
-        self.on_chain.message_is_spent(nonce)
-    }
+/// One line of the output of the [`ReadView::dump_owner`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", content = "entry", rename_all = "snake_case")]
+pub enum OwnerDumpEntry {
+    /// An unspent coin of the owner.
+    Coin(Coin),
+    /// An unspent message to the owner.
+    Message(Message),
+}
 
-    fn message_exists(&self, nonce: &Nonce) -> StorageResult<bool> {
-        self.on_chain.message_exists(nonce)
+/// Errors that can occur while reading the data from the [`ReadView`].
+#[derive(Debug, thiserror::Error)]
+pub enum ReadViewError {
+    #[error(
+        "The data at height {requested} is not yet available, the view is pinned at {pinned}"
+    )]
+    NotYetAvailable {
+        requested: BlockHeight,
+        pinned: BlockHeight,
+    },
+    #[error("Failed to read the block from the {0} database")]
+    Block(BlockSource),
+    #[error(
+        "The off-chain database contains the block {last_old_block} from before the regenesis, \
+        which is above the on-chain genesis height {genesis_height}"
+    )]
+    RegenesisOverlap {
+        last_old_block: BlockHeight,
+        genesis_height: BlockHeight,
+    },
+    #[error(
+        "The on-chain height {on_chain} and the off-chain height {off_chain} \
+        differ by more than {max_skew} blocks"
+    )]
+    ViewSkewExceeded {
+        on_chain: BlockHeight,
+        off_chain: BlockHeight,
+        max_skew: u32,
+    },
+    #[error("The chain has no data from before the regenesis")]
+    PreRegenesisDataUnavailable,
+}
+
+/// The database that produced the block for the [`ReadView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockSource {
+    /// The on-chain database with blocks produced after the regenesis.
+    OnChain,
+    /// The off-chain database with blocks from before the regenesis.
+    OffChain,
+}
+
+impl core::fmt::Display for BlockSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BlockSource::OnChain => write!(f, "on-chain"),
+            BlockSource::OffChain => write!(f, "off-chain"),
+        }
     }
 }
 
-impl DatabaseRelayedTransactions for ReadView {
-    fn transaction_status(
-        &self,
-        id: Bytes32,
-    ) -> StorageResult<Option<RelayedTransactionStatus>> {
-        let maybe_status = self.off_chain.relayed_tx_status(id)?;
-        Ok(maybe_status)
+impl BlockSource {
+    /// Attaches the source of the block to the `error`.
+    fn tag(self, error: StorageError) -> StorageError {
+        StorageError::Other(
+            anyhow::Error::from(error).context(ReadViewError::Block(self)),
+        )
     }
 }
 
-impl DatabaseContracts for ReadView {
-    fn contract_balances(
-        &self,
-        contract: ContractId,
-        start_asset: Option<AssetId>,
-        direction: IterDirection,
-    ) -> BoxedIter<StorageResult<ContractBalance>> {
-        self.on_chain
-            .contract_balances(contract, start_asset, direction)
+/// Sums the `amounts` as `u128`, returning an error instead of wrapping
+/// or saturating if the sum doesn't fit.
+fn checked_sum(
+    amounts: impl IntoIterator<Item = StorageResult<u64>>,
+) -> StorageResult<u128> {
+    amounts.into_iter().try_fold(0u128, |sum, amount| {
+        sum.checked_add(u128::from(amount?)).ok_or_else(|| {
+            StorageError::Other(anyhow::anyhow!("The sum of the amounts overflows"))
+        })
+    })
+}
+
+/// Returns the contracts whose state or balance may be changed by the `receipt`.
+fn touched_contracts(receipt: &Receipt) -> impl Iterator<Item = ContractId> {
+    let contracts = match receipt {
+        Receipt::Call { id, to, .. } | Receipt::Transfer { id, to, .. } => {
+            [Some(*id), Some(*to)]
+        }
+        Receipt::TransferOut { id, .. } => [Some(*id), None],
+        Receipt::Mint { contract_id, .. } | Receipt::Burn { contract_id, .. } => {
+            [Some(*contract_id), None]
+        }
+        _ => [None, None],
+    };
+    // The receipts produced by the script itself have the zeroed contract id.
+    contracts
+        .into_iter()
+        .flatten()
+        .filter(|contract_id| *contract_id != ContractId::zeroed())
+}
+
+/// Returns `true` if the output at the `index` of the executed `transaction` created a coin.
+/// The executor doesn't create coins for the outputs without any amount.
+fn creates_coin(transaction: &Transaction, index: usize) -> bool {
+    let outputs = match transaction {
+        Transaction::Script(tx) => tx.outputs(),
+        Transaction::Create(tx) => tx.outputs(),
+        Transaction::Upgrade(tx) => tx.outputs(),
+        Transaction::Upload(tx) => tx.outputs(),
+        Transaction::Mint(_) => return false,
+    };
+    matches!(
+        outputs.get(index),
+        Some(
+            Output::Coin { amount, .. }
+                | Output::Change { amount, .. }
+                | Output::Variable { amount, .. }
+        ) if *amount > 0
+    )
+}
+
+/// Whether the block was produced before or after the regenesis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockOrigin {
+    /// The block is below the on-chain genesis height and comes from the previous network.
+    PreRegenesis,
+    /// The block is at or above the on-chain genesis height.
+    PostRegenesis,
+}
+
+impl ReadViewError {
+    /// Returns `Ok(None)` instead of [`ReadViewError::PreRegenesisDataUnavailable`]
+    /// for the lookups that only fall back to the data from before the regenesis.
+    pub(crate) fn none_without_history<T>(
+        result: StorageResult<Option<T>>,
+    ) -> StorageResult<Option<T>> {
+        match result {
+            Err(StorageError::Other(error))
+                if matches!(
+                    error.downcast_ref::<ReadViewError>(),
+                    Some(ReadViewError::PreRegenesisDataUnavailable)
+                ) =>
+            {
+                Ok(None)
+            }
+            result => result,
+        }
     }
 }
 
-impl DatabaseChain for ReadView {
-    fn da_height(&self) -> StorageResult<DaBlockHeight> {
-        self.on_chain.da_height()
+impl From<ReadViewError> for StorageError {
+    fn from(error: ReadViewError) -> Self {
+        StorageError::Other(error.into())
     }
 }
 
-impl DatabaseMessageProof for ReadView {
-    fn block_history_proof(
-        &self,
-        message_block_height: &BlockHeight,
-        commit_block_height: &BlockHeight,
-    ) -> StorageResult<MerkleProof> {
-        self.on_chain
-            .block_history_proof(message_block_height, commit_block_height)
+/// The heights of the chain as seen by the [`ReadView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Heights {
+    /// The height at which the view is pinned.
+    pub latest: BlockHeight,
+    /// The genesis height of the on-chain database.
+    pub genesis: BlockHeight,
+    /// The DA height of the chain.
+    pub da: DaBlockHeight,
+}
+
+/// The order in which [`ReadView::blocks`] reads the blocks from before the regenesis
+/// in the off-chain database and the blocks in the on-chain database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamPlan {
+    /// Only the on-chain blocks from the start height.
+    OnChainOnly,
+    /// Only the off-chain blocks from the start height.
+    OffChainOnly,
+    /// The off-chain blocks from the start height, followed by all on-chain blocks.
+    OffThenOn,
+    /// The on-chain blocks from the start height, followed by all off-chain blocks.
+    OnThenOff,
+}
+
+/// Decides which databases serve the blocks starting at the `height` in the `direction`.
+///
+/// The on-chain database starts at the `genesis` height. Without a start `height`, or
+/// without on-chain blocks, both databases are read in the order of the `direction`.
+fn block_stream_plan(
+    height: Option<BlockHeight>,
+    genesis: Option<BlockHeight>,
+    direction: IterDirection,
+) -> StreamPlan {
+    let on_chain_start = match (height, genesis) {
+        (Some(height), Some(genesis)) => Some(height >= genesis),
+        _ => None,
+    };
+    match (on_chain_start, direction) {
+        (Some(true), IterDirection::Forward) => StreamPlan::OnChainOnly,
+        (Some(false), IterDirection::Reverse) => StreamPlan::OffChainOnly,
+        (Some(false) | None, IterDirection::Forward) => StreamPlan::OffThenOn,
+        (Some(true) | None, IterDirection::Reverse) => StreamPlan::OnThenOff,
     }
 }
 
-impl OnChainDatabase for ReadView {}
+pub struct ReadView {
+    /// The height at which both on-chain and off-chain views are pinned.
+    height: BlockHeight,
+    /// The latest height of the on-chain database when the view was created.
+    on_chain_height: Option<BlockHeight>,
+    /// The latest height of the off-chain database when the view was created.
+    off_chain_height: Option<BlockHeight>,
+    /// The genesis height of the on-chain database, fetched on the first use.
+    genesis_height: OnceLock<BlockHeight>,
+    /// Set once the blocks from before the regenesis are checked to not overlap
+    /// with the on-chain blocks.
+    regenesis_checked: OnceLock<()>,
+    /// The retries of the reads from the views.
+    config: ReadViewConfig,
+    /// The message proofs shared with the other views of the [`ReadDatabase`].
+    message_proofs: Arc<MessageProofCache>,
+    on_chain: OnChainView,
+    off_chain: OffChainView,
+}
 
-impl OffChainDatabase for ReadView {
-    fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight> {
-        self.off_chain.block_height(block_id)
+impl ReadView {
+    /// Returns the height at which the view is pinned.
+    pub fn pinned_height(&self) -> BlockHeight {
+        self.height
     }
 
-    fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
-        self.off_chain.tx_status(tx_id)
+    /// Returns the latest `(on_chain_height, off_chain_height)` observed when
+    /// the view was created. The off-chain height is `None` if the off-chain
+    /// database hasn't processed any block yet.
+    pub fn database_heights(&self) -> (Option<BlockHeight>, Option<BlockHeight>) {
+        (self.on_chain_height, self.off_chain_height)
     }
 
-    fn owned_coins_ids(
+    /// Returns the latest, genesis and DA heights of the chain, all resolved
+    /// from the same pinned view.
+    pub fn heights(&self) -> StorageResult<Heights> {
+        Ok(Heights {
+            latest: self.height,
+            genesis: self.genesis_height()?,
+            da: DatabaseChain::da_height(self)?,
+        })
+    }
+
+    /// Returns the on-chain genesis height if the off-chain database has blocks
+    /// from before the regenesis below it, or `None` if the chain has no history
+    /// from before the regenesis or no on-chain blocks yet.
+    ///
+    /// A block from before the regenesis at the genesis height itself is a duplicate
+    /// of the on-chain genesis block, so it doesn't count as the history.
+    /// At most one block from before the regenesis is read.
+    pub fn regenesis_boundary(&self) -> StorageResult<Option<BlockHeight>> {
+        let genesis_height = match self.genesis_height() {
+            Ok(genesis_height) => genesis_height,
+            Err(StorageError::NotFound(_, _)) => return Ok(None),
+            Err(err) => return Err(BlockSource::OnChain.tag(err)),
+        };
+        let first_old_block = self
+            .off_chain_blocks(None, IterDirection::Forward)
+            .next()
+            .transpose()?;
+        let has_history = first_old_block
+            .is_some_and(|block| *block.header().height() < genesis_height);
+        Ok(has_history.then_some(genesis_height))
+    }
+
+    /// Returns `true` if the on-chain and off-chain databases had the same
+    /// latest height when the view was created.
+    pub fn is_consistent(&self) -> bool {
+        self.on_chain_height == self.off_chain_height
+    }
+
+    /// Warns if the on-chain and off-chain heights of the view diverge and
+    /// the [`ReadViewConfig::check_consistency`] is enabled.
+    /// Returns `true` if the warning was logged.
+    fn check_consistency(&self) -> bool {
+        if !self.config.check_consistency || self.is_consistent() {
+            return false
+        }
+        tracing::warn!(
+            pinned_height = %self.height,
+            on_chain_height = ?self.on_chain_height,
+            off_chain_height = ?self.off_chain_height,
+            "The on-chain and off-chain heights of the view diverge"
+        );
+        true
+    }
+
+    /// Warns if the off-chain `read` returned the data of the block at the `height`
+    /// above the pinned height and the [`ReadViewConfig::check_consistency`] is enabled.
+    /// Returns `true` if the warning was logged.
+    fn check_off_chain_height(&self, read: &str, height: BlockHeight) -> bool {
+        if !self.config.check_consistency || height <= self.height {
+            return false
+        }
+        tracing::warn!(
+            pinned_height = %self.height,
+            on_chain_height = ?self.on_chain_height,
+            off_chain_height = ?self.off_chain_height,
+            "The off-chain `{read}` returned the data of the block {height}, \
+            which is above the pinned height"
+        );
+        true
+    }
+
+    /// Runs the `read`, retrying it after the transient errors of the underlying datastore
+    /// as configured by the [`ReadViewConfig`].
+    fn retry<T>(&self, read: impl FnMut() -> StorageResult<T>) -> StorageResult<T> {
+        self.check_consistency();
+        self.config.retry(read)
+    }
+
+    /// Opens the iterator with the `open`, retrying it like [`Self::retry`] if its first
+    /// item is a transient error. The errors after the first item are returned as is,
+    /// so the iteration never restarts mid-stream.
+    fn retry_iter<'a, T: 'a>(
+        &self,
+        mut open: impl FnMut() -> BoxedIter<'a, StorageResult<T>>,
+    ) -> BoxedIter<'a, StorageResult<T>> {
+        self.check_consistency();
+        let mut retries = self.config.retries();
+        loop {
+            let mut iter = open();
+            match iter.next() {
+                Some(Err(err)) if retries.retry(&err) => continue,
+                first => return first.into_iter().chain(iter).into_boxed(),
+            }
+        }
+    }
+
+    /// Returns the coins owned by the `owner` together with their ids.
+    ///
+    /// The owned coins index lives in the off-chain database and can still reference
+    /// a coin that is already spent in the on-chain database. Such coins are skipped.
+    pub fn owned_coins(
         &self,
         owner: &Address,
         start_coin: Option<UtxoId>,
         direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(UtxoId, CompressedCoin)>> {
+        self.off_chain
+            .owned_coins_ids(owner, start_coin, direction)
+            .filter_map(|result| {
+                result
+                    .and_then(|utxo_id| {
+                        let coin = self.storage::<Coins>().get(&utxo_id)?;
+                        Ok(coin.map(|coin| (utxo_id, coin.into_owned())))
+                    })
+                    .transpose()
+            })
+            .into_boxed()
+    }
+
+    /// Returns the ids of the coins with the `asset_id` owned by the `owner`.
+    ///
+    /// There is no index of coins by asset, so the asset of each owned coin
+    /// is looked up in the on-chain database, one lookup per candidate.
+    pub fn owned_coins_ids_by_asset(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        start: Option<UtxoId>,
+        direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<UtxoId>> {
-        self.off_chain.owned_coins_ids(owner, start_coin, direction)
+        let asset_id = *asset_id;
+        self.owned_coins(owner, start, direction)
+            .filter_map(move |result| match result {
+                Ok((utxo_id, coin)) => {
+                    (*coin.asset_id() == asset_id).then_some(Ok(utxo_id))
+                }
+                Err(err) => Some(Err(err)),
+            })
+            .into_boxed()
     }
 
-    fn owned_message_ids(
+    /// Returns the total amount of the coins with the `asset_id` owned by the `owner`.
+    ///
+    /// The amounts are summed as `u128`, so the total can't overflow. The balance
+    /// reflects the unspent coins of the view.
+    pub fn owned_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<u128> {
+        checked_sum(
+            self.owned_coins(owner, None, IterDirection::Forward)
+                .filter_map_ok(|(_, coin)| {
+                    (coin.asset_id() == asset_id).then_some(*coin.amount())
+                }),
+        )
+    }
+
+    /// Returns the distinct asset ids of the coins owned by the `owner`, each once,
+    /// in the order of their first coin by the `UtxoId`.
+    ///
+    /// The yielded asset ids are remembered to skip the duplicates, so the memory
+    /// usage is proportional to the number of distinct assets held by the `owner`,
+    /// not to the number of coins.
+    pub fn owned_asset_ids(
+        &self,
+        owner: &Address,
+    ) -> BoxedIter<'_, StorageResult<AssetId>> {
+        let mut seen = HashSet::new();
+        self.owned_coins(owner, None, IterDirection::Forward)
+            .filter_map(move |result| match result {
+                Ok((_, coin)) => {
+                    let asset_id = *coin.asset_id();
+                    seen.insert(asset_id).then_some(Ok(asset_id))
+                }
+                Err(err) => Some(Err(err)),
+            })
+            .into_boxed()
+    }
+
+    /// Returns the messages owned by the `owner` together with their nonces,
+    /// in the order of [`OffChainDatabase::owned_message_ids`].
+    ///
+    /// The index can still reference a message that is already spent and removed
+    /// from the on-chain database, so such messages are skipped.
+    pub fn owned_messages(
         &self,
         owner: &Address,
         start_message_id: Option<Nonce>,
         direction: IterDirection,
-    ) -> BoxedIter<'_, StorageResult<Nonce>> {
-        self.off_chain
-            .owned_message_ids(owner, start_message_id, direction)
+    ) -> BoxedIter<'_, StorageResult<(Nonce, Message)>> {
+        self.owned_message_ids(owner, start_message_id, direction)
+            .filter_map(|result| {
+                result
+                    .and_then(|nonce| {
+                        let message = self.storage::<Messages>().get(&nonce)?;
+                        Ok(message.map(|message| (nonce, message.into_owned())))
+                    })
+                    .transpose()
+            })
+            .into_boxed()
     }
 
-    fn owned_transactions_ids(
+    /// Writes the unspent coins and then the unspent messages of the `owner` into
+    /// the `writer` as newline-delimited JSON, one [`OwnerDumpEntry`] per line.
+    ///
+    /// The entries are written as they are read, so the memory usage doesn't depend
+    /// on the number of entries. Meant for debugging, the output format isn't stable.
+    pub fn dump_owner(
         &self,
-        owner: Address,
-        start: Option<TxPointer>,
-        direction: IterDirection,
-    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
-        self.off_chain
-            .owned_transactions_ids(owner, start, direction)
+        owner: &Address,
+        mut writer: impl std::io::Write,
+    ) -> StorageResult<()> {
+        let coins = self
+            .owned_coins(owner, None, IterDirection::Forward)
+            .map(|result| {
+                result
+                    .map(|(utxo_id, coin)| OwnerDumpEntry::Coin(coin.uncompress(utxo_id)))
+            });
+        let messages = self
+            .owned_messages(owner, None, IterDirection::Forward)
+            .map(|result| result.map(|(_, message)| OwnerDumpEntry::Message(message)));
+
+        for entry in coins.chain(messages) {
+            serde_json::to_writer(&mut writer, &entry?)
+                .map_err(|error| StorageError::Other(error.into()))?;
+            writer
+                .write_all(b"\n")
+                .map_err(|error| StorageError::Other(error.into()))?;
+        }
+        writer
+            .flush()
+            .map_err(|error| StorageError::Other(error.into()))
     }
 
-    fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
-        self.off_chain.contract_salt(contract_id)
+    /// Returns `true` if the coin with the `utxo_id` is unspent, without fetching it.
+    /// Spent coins are removed from the coins table, so an absent coin is either
+    /// spent or never existed.
+    pub fn coin_exists(&self, utxo_id: &UtxoId) -> StorageResult<bool> {
+        self.storage::<Coins>().contains_key(utxo_id)
     }
 
-    fn old_blocks(
+    /// Returns the coin with the `utxo_id` if it is unspent, or whether it was spent.
+    ///
+    /// A missing coin is [`CoinStatus::Spent`] if the transaction that created it is
+    /// known, including the transactions from before the regenesis, and its output
+    /// at the index created a coin. The coins of the genesis snapshot have no creating
+    /// transaction, so once spent, they are [`CoinStatus::Unknown`].
+    pub fn coin_with_status(&self, utxo_id: &UtxoId) -> StorageResult<CoinStatus> {
+        if let Some(coin) = self.storage::<Coins>().get(utxo_id)? {
+            return Ok(CoinStatus::Unspent(coin.into_owned()))
+        }
+
+        let tx_id = utxo_id.tx_id();
+        let transaction = match self.storage::<Transactions>().get(tx_id)? {
+            Some(transaction) => Some(transaction.into_owned()),
+            None => ReadViewError::none_without_history(self.old_transaction(tx_id))?,
+        };
+        let created = transaction.is_some_and(|transaction| {
+            creates_coin(&transaction, usize::from(utxo_id.output_index()))
+        });
+        if created {
+            Ok(CoinStatus::Spent)
+        } else {
+            Ok(CoinStatus::Unknown)
+        }
+    }
+
+    /// Returns the `(owner, utxo_id, amount)` of the unspent coins with the `asset_id`
+    /// across all owners, ordered by the `UtxoId`.
+    ///
+    /// There is no index of coins by asset, so the whole coins table is scanned
+    /// starting from the `start` coin, and the cost grows with the number of all
+    /// unspent coins, not only the coins of the asset.
+    pub fn coins_by_asset(
         &self,
-        height: Option<BlockHeight>,
+        asset_id: &AssetId,
+        start: Option<UtxoId>,
         direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Address, UtxoId, u64)>> {
+        let asset_id = *asset_id;
+        self.all_coins(start, direction)
+            .filter_map(move |result| match result {
+                Ok((utxo_id, coin)) => (*coin.asset_id() == asset_id)
+                    .then(|| Ok((*coin.owner(), utxo_id, *coin.amount()))),
+                Err(err) => Some(Err(err)),
+            })
+            .into_boxed()
+    }
+
+    /// Returns the block with the `block_id`, or `None` if the id is unknown.
+    ///
+    /// The id is resolved into the height by the off-chain database. Blocks from before
+    /// the regenesis are fetched from the off-chain database, the rest from the on-chain one.
+    /// Blocks above the pinned height are not part of the view, so they are `None` too.
+    pub fn block_by_id(
+        &self,
+        block_id: &BlockId,
+    ) -> StorageResult<Option<CompressedBlock>> {
+        let height = match self.retry(|| self.off_chain.block_height(block_id)) {
+            Ok(height) => height,
+            Err(StorageError::NotFound(_, _)) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        if height > self.height {
+            return Ok(None)
+        }
+
+        let block = self
+            .blocks(Some(height), IterDirection::Forward)
+            .next()
+            .transpose()?;
+        Ok(block.filter(|block| *block.header().height() == height))
+    }
+
+    /// Returns the blocks at the `heights`, preserving their order.
+    ///
+    /// Like [`DatabaseBlocks::blocks`], each height is resolved on either side of
+    /// the regenesis. A missing block yields the `NotFound` error in its place, and
+    /// a height above the pinned height yields [`ReadViewError::NotYetAvailable`].
+    pub fn blocks_by_heights(
+        &self,
+        heights: &[BlockHeight],
     ) -> BoxedIter<'_, StorageResult<CompressedBlock>> {
-        self.off_chain.old_blocks(height, direction)
+        heights
+            .to_vec()
+            .into_iter()
+            .map(|height| {
+                self.ensure_available(height)?;
+                self.blocks(Some(height), IterDirection::Forward)
+                    .next()
+                    .transpose()?
+                    .filter(|block| *block.header().height() == height)
+                    .ok_or(not_found!(FuelBlocks))
+            })
+            .into_boxed()
     }
 
-    fn old_block_consensus(&self, height: BlockHeight) -> StorageResult<Consensus> {
-        self.off_chain.old_block_consensus(height)
+    /// Returns the number of transactions in the block at the `height`.
+    ///
+    /// The count is the length of the transaction id list of the [`CompressedBlock`],
+    /// so the transactions themselves are not read. The height is resolved like in
+    /// [`Self::blocks_by_heights`], and an unknown height is the `NotFound` error.
+    pub fn block_transaction_count(&self, height: BlockHeight) -> StorageResult<u32> {
+        let block = self
+            .blocks_by_heights(core::slice::from_ref(&height))
+            .next()
+            .unwrap_or_else(|| Err(not_found!(FuelBlocks)))?;
+        Ok(u32::try_from(block.transactions().len()).unwrap_or(u32::MAX))
     }
 
-    fn old_transaction(
+    /// Returns the balance of the `base_asset` held by the `contract` widened to `u128`,
+    /// so it can be aggregated with other totals without overflowing `u64`.
+    ///
+    /// A contract holds a single `u64` balance per asset, so there is nothing to sum
+    /// here. The only aggregations on the balance paths are `checked_sum` for the
+    /// owned coins and messages, and the `i128` deltas of the contract balance changes.
+    pub fn contract_total_value(
         &self,
-        id: &TxId,
-    ) -> StorageResult<Option<fuel_core_types::fuel_tx::Transaction>> {
-        self.off_chain.old_transaction(id)
+        contract: ContractId,
+        base_asset: &AssetId,
+    ) -> StorageResult<u128> {
+        Ok(self
+            .contract_asset_balance(contract, base_asset)?
+            .map_or(0, u128::from))
     }
 
-    fn relayed_tx_status(
+    /// Returns the contracts whose state or balance may have changed in the block
+    /// at the `height`, each once and ordered by the contract id.
+    ///
+    /// The contracts are derived from the receipts of the successful transactions
+    /// of the block: the called contracts, the contracts sending or receiving
+    /// a transfer, and the contracts minting or burning an asset. It requires
+    /// the receipts to be retained in the transaction statuses of the off-chain
+    /// database, and fails with the `NotFound` error if a status is missing.
+    pub fn contracts_touched_in_block(
         &self,
-        id: Bytes32,
-    ) -> StorageResult<Option<RelayedTransactionStatus>> {
-        self.off_chain.relayed_tx_status(id)
+        height: BlockHeight,
+    ) -> BoxedIter<'_, StorageResult<ContractId>> {
+        let touched = || -> StorageResult<BTreeSet<ContractId>> {
+            let block = self
+                .blocks_by_heights(core::slice::from_ref(&height))
+                .next()
+                .unwrap_or_else(|| Err(not_found!(FuelBlocks)))?;
+            let mut contracts = BTreeSet::new();
+            for tx_id in block.transactions() {
+                if let TransactionStatus::Success { receipts, .. } =
+                    self.tx_status(tx_id)?
+                {
+                    contracts.extend(receipts.iter().flat_map(touched_contracts));
+                }
+            }
+            Ok(contracts)
+        };
+
+        match touched() {
+            Ok(contracts) => contracts.into_iter().map(Ok).into_boxed(),
+            Err(err) => core::iter::once(Err(err)).into_boxed(),
+        }
+    }
+
+    /// Returns the genesis block of the on-chain database, the first block
+    /// after the regenesis, without knowing its height.
+    ///
+    /// It is the `NotFound` error if the on-chain database has no blocks yet.
+    pub fn genesis_block(&self) -> StorageResult<CompressedBlock> {
+        let genesis_height = self.genesis_height()?;
+        self.storage::<FuelBlocks>()
+            .get(&genesis_height)
+            .map_err(|error| BlockSource::OnChain.tag(error))?
+            .map(|block| block.into_owned())
+            .ok_or(not_found!(FuelBlocks))
+    }
+
+    /// Returns up to `n` of the latest blocks in descending height order, starting
+    /// from the pinned height and crossing the regenesis if the chain is short.
+    /// Fewer than `n` blocks are returned if the chain doesn't have that many.
+    pub fn latest_blocks(&self, n: usize) -> StorageResult<Vec<CompressedBlock>> {
+        self.blocks(None, IterDirection::Reverse).take(n).collect()
+    }
+
+    /// Returns whether the view has a block next to the `height` in the `direction`,
+    /// without iterating further.
+    ///
+    /// Blocks after the regenesis are checked by the key, so no block is read.
+    /// Before the regenesis, at most one compressed block is read.
+    /// Blocks above the pinned height are never reported.
+    pub fn has_block_after(
+        &self,
+        height: BlockHeight,
+        direction: IterDirection,
+    ) -> StorageResult<bool> {
+        let next = match direction {
+            IterDirection::Forward => height.succ(),
+            IterDirection::Reverse => height.pred(),
+        };
+        let Some(next) = next.filter(|next| *next <= self.height) else {
+            return Ok(false)
+        };
+
+        match self.genesis_height() {
+            Ok(genesis_height) if next >= genesis_height => self
+                .storage::<FuelBlocks>()
+                .contains_key(&next)
+                .map_err(|error| BlockSource::OnChain.tag(error)),
+            Ok(_) | Err(StorageError::NotFound(_, _)) => {
+                let block = self
+                    .off_chain_blocks(Some(next), IterDirection::Forward)
+                    .next()
+                    .transpose()?;
+                Ok(block.is_some_and(|block| *block.header().height() == next))
+            }
+            Err(err) => Err(BlockSource::OnChain.tag(err)),
+        }
+    }
+
+    /// Returns whether the block at each of the `heights` exists, in the order
+    /// of the `heights`.
+    ///
+    /// The blocks are checked by the key on both sides of the regenesis,
+    /// so no block is read. The heights above the pinned height don't exist.
+    pub fn blocks_exist(&self, heights: &[BlockHeight]) -> StorageResult<Vec<bool>> {
+        let genesis_height = match self.genesis_height() {
+            Ok(genesis_height) => Some(genesis_height),
+            Err(StorageError::NotFound(_, _)) => None,
+            Err(err) => return Err(BlockSource::OnChain.tag(err)),
+        };
+
+        heights
+            .iter()
+            .map(|height| match genesis_height {
+                _ if *height > self.height => Ok(false),
+                Some(genesis_height) if *height >= genesis_height => self
+                    .storage::<FuelBlocks>()
+                    .contains_key(height)
+                    .map_err(|error| BlockSource::OnChain.tag(error)),
+                _ => self
+                    .old_block_exists(*height)
+                    .map_err(|error| BlockSource::OffChain.tag(error)),
+            })
+            .collect()
+    }
+
+    /// Returns the [`MerkleProof`] for each `(message_block_height, commit_block_height)`
+    /// pair of the `requests` like [`DatabaseMessageProof::block_history_proof`],
+    /// generating up to `concurrency` proofs at a time on the blocking tasks.
+    ///
+    /// The proofs are returned in the order of the `requests`. The first error in that
+    /// order is returned, and the proofs that haven't started yet are cancelled.
+    /// The proofs already being generated run to completion, and their results are dropped.
+    pub async fn block_history_proofs_concurrent(
+        &self,
+        requests: &[(BlockHeight, BlockHeight)],
+        concurrency: usize,
+    ) -> StorageResult<Vec<MerkleProof>> {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let tasks = requests
+            .iter()
+            .copied()
+            .map(|(message_height, commit_height)| {
+                let on_chain = self.on_chain.clone();
+                let config = self.config;
+                let cancelled = cancelled.clone();
+                tokio::task::spawn_blocking(move || {
+                    if cancelled.load(Ordering::Relaxed) {
+                        return Err(StorageError::Other(anyhow::anyhow!(
+                            "The generation of the proof was cancelled"
+                        )));
+                    }
+                    config.retry(|| {
+                        on_chain.block_history_proof(&message_height, &commit_height)
+                    })
+                })
+            });
+
+        futures::stream::iter(tasks)
+            .buffered(concurrency.max(1))
+            .map(|joined| match joined {
+                Ok(proof) => proof,
+                Err(err) => Err(StorageError::Other(err.into())),
+            })
+            .inspect_err(|_| cancelled.store(true, Ordering::Relaxed))
+            .try_collect()
+            .await
+    }
+
+    /// Returns the consensus of the block at the `height` on either side of the regenesis.
+    ///
+    /// Like [`DatabaseBlocks::blocks`], the heights below the on-chain genesis height
+    /// are served by the off-chain database, and the rest by the on-chain one.
+    /// Heights above the pinned height are rejected with [`ReadViewError::NotYetAvailable`].
+    pub fn block_consensus(&self, height: BlockHeight) -> StorageResult<Consensus> {
+        self.ensure_available(height)?;
+
+        let genesis_height = match self.genesis_height() {
+            Ok(genesis_height) => Some(genesis_height),
+            Err(StorageError::NotFound(_, _)) => None,
+            Err(err) => return Err(err),
+        };
+        match genesis_height {
+            Some(genesis_height) if height >= genesis_height => self
+                .storage::<SealedBlockConsensus>()
+                .get(&height)?
+                .map(|consensus| consensus.into_owned())
+                .ok_or(not_found!(SealedBlockConsensus)),
+            _ => self.old_block_consensus(height),
+        }
+    }
+
+    /// Returns the same blocks as [`DatabaseBlocks::blocks`], tagged with their [`BlockOrigin`].
+    ///
+    /// The origin is derived from the on-chain genesis height. Without on-chain blocks,
+    /// all blocks are from before the regenesis.
+    pub fn blocks_tagged(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(BlockOrigin, CompressedBlock)>> {
+        let genesis_height = match self.genesis_height() {
+            Ok(genesis_height) => Some(genesis_height),
+            Err(StorageError::NotFound(_, _)) => None,
+            Err(err) => {
+                return core::iter::once(Err(BlockSource::OnChain.tag(err))).into_boxed()
+            }
+        };
+
+        self.blocks(height, direction)
+            .map(move |result| {
+                result.map(|block| {
+                    let origin = match genesis_height {
+                        Some(genesis_height)
+                            if *block.header().height() >= genesis_height =>
+                        {
+                            BlockOrigin::PostRegenesis
+                        }
+                        _ => BlockOrigin::PreRegenesis,
+                    };
+                    (origin, block)
+                })
+            })
+            .into_boxed()
+    }
+
+    /// Returns the genesis height of the on-chain database.
+    ///
+    /// The genesis height doesn't change during the lifetime of the view,
+    /// so the storage is queried only once.
+    fn genesis_height(&self) -> StorageResult<BlockHeight> {
+        if let Some(height) = self.genesis_height.get() {
+            return Ok(*height)
+        }
+        let height = self.retry(|| self.on_chain.latest_genesis_height())?;
+        Ok(*self.genesis_height.get_or_init(|| height))
+    }
+
+    /// Returns an error if the off-chain database contains blocks from before
+    /// the regenesis above the on-chain genesis height. A block at the genesis
+    /// height is tolerated, see [`Self::dedup_regenesis_seam`].
+    fn ensure_no_regenesis_overlap(&self) -> StorageResult<()> {
+        if self.regenesis_checked.get().is_some() {
+            return Ok(())
+        }
+
+        let genesis_height = match self.genesis_height() {
+            Ok(height) => height,
+            // Without on-chain blocks there is nothing to overlap with.
+            Err(StorageError::NotFound(_, _)) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let last_old_block = self
+            .off_chain_blocks(None, IterDirection::Reverse)
+            .next()
+            .transpose()?
+            .map(|block| *block.header().height());
+
+        if let Some(last_old_block) = last_old_block {
+            if last_old_block > genesis_height {
+                return Err(ReadViewError::RegenesisOverlap {
+                    last_old_block,
+                    genesis_height,
+                }
+                .into())
+            }
+        }
+
+        let _ = self.regenesis_checked.set(());
+        Ok(())
+    }
+
+    /// Returns an error if the `height` is above the pinned height of the view.
+    fn ensure_available(&self, height: BlockHeight) -> StorageResult<()> {
+        if height > self.height {
+            return Err(ReadViewError::NotYetAvailable {
+                requested: height,
+                pinned: self.height,
+            }
+            .into())
+        }
+        Ok(())
+    }
+
+    /// Returns whether the off-chain database has any block from before the regenesis.
+    fn has_pre_regenesis_data(&self) -> StorageResult<bool> {
+        Ok(self
+            .off_chain_blocks(None, IterDirection::Forward)
+            .next()
+            .transpose()?
+            .is_some())
+    }
+
+    /// Replaces a not-found `error` for data from before the regenesis with
+    /// [`ReadViewError::PreRegenesisDataUnavailable`] if the chain has no such data at all.
+    fn pre_regenesis_error(&self, error: StorageError) -> StorageError {
+        if !matches!(error, StorageError::NotFound(_, _)) {
+            return error
+        }
+        match self.has_pre_regenesis_data() {
+            Ok(false) => ReadViewError::PreRegenesisDataUnavailable.into(),
+            Ok(true) => error,
+            Err(err) => err,
+        }
+    }
+
+    fn chained_blocks(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<CompressedBlock>> {
+        // Chain together blocks from the off-chain db and the on-chain db
+        // The blocks in off-chain db, if any, are from time before regenesis
+        if let Err(err) = self.ensure_no_regenesis_overlap() {
+            return core::iter::once(Err(err)).into_boxed()
+        }
+
+        let genesis_height = match self.genesis_height() {
+            Ok(genesis_height) => Some(genesis_height),
+            // Without on-chain blocks there is no seam to deduplicate.
+            Err(StorageError::NotFound(_, _)) if height.is_none() => None,
+            Err(err) => {
+                return core::iter::once(Err(BlockSource::OnChain.tag(err))).into_boxed()
+            }
+        };
+
+        match block_stream_plan(height, genesis_height, direction) {
+            StreamPlan::OnChainOnly => {
+                self.on_chain_blocks(height, direction).into_boxed()
+            }
+            StreamPlan::OffChainOnly => Self::dedup_regenesis_seam(
+                self.off_chain_blocks(height, direction),
+                genesis_height,
+            )
+            .into_boxed(),
+            StreamPlan::OffThenOn => Self::dedup_regenesis_seam(
+                self.off_chain_blocks(height, direction),
+                genesis_height,
+            )
+            .chain(self.on_chain_blocks(None, direction))
+            .into_boxed(),
+            StreamPlan::OnThenOff => self
+                .on_chain_blocks(height, direction)
+                .chain(Self::dedup_regenesis_seam(
+                    self.off_chain_blocks(None, direction),
+                    genesis_height,
+                ))
+                .into_boxed(),
+        }
+    }
+
+    /// Drops the block from before the regenesis at the `genesis_height`. Such a block
+    /// is a duplicate of the on-chain genesis block caused by an off-by-one in the snapshot,
+    /// and the on-chain copy is authoritative.
+    fn dedup_regenesis_seam<'a>(
+        old_blocks: impl Iterator<Item = StorageResult<CompressedBlock>> + 'a,
+        genesis_height: Option<BlockHeight>,
+    ) -> impl Iterator<Item = StorageResult<CompressedBlock>> + 'a {
+        old_blocks.filter(move |result| match result {
+            Ok(block) if Some(*block.header().height()) == genesis_height => {
+                tracing::warn!(
+                    "The block {} from before the regenesis duplicates the on-chain \
+                    genesis block, using the on-chain block",
+                    block.header().height()
+                );
+                false
+            }
+            _ => true,
+        })
+    }
+
+    /// Returns blocks from the on-chain database, tagging errors with [`BlockSource::OnChain`].
+    fn on_chain_blocks(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<CompressedBlock>> + '_ {
+        self.retry_iter(|| self.on_chain.blocks(height, direction))
+            .map(|result| result.map_err(|error| BlockSource::OnChain.tag(error)))
+    }
+
+    /// Returns blocks from before the regenesis, tagging errors with [`BlockSource::OffChain`].
+    fn off_chain_blocks(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<CompressedBlock>> + '_ {
+        self.retry_iter(|| self.off_chain.old_blocks(height, direction))
+            .map(|result| result.map_err(|error| BlockSource::OffChain.tag(error)))
+    }
+}
+
+impl DatabaseBlocks for ReadView {
+    fn blocks(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<CompressedBlock>> {
+        if let Some(height) = height {
+            if let Err(err) = self.ensure_available(height) {
+                return core::iter::once(Err(err)).into_boxed()
+            }
+        }
+
+        match direction {
+            IterDirection::Forward => {
+                let pinned_height = self.height;
+                self.chained_blocks(height, direction)
+                    .take_while(move |result| match result {
+                        Ok(block) => *block.header().height() <= pinned_height,
+                        Err(_) => true,
+                    })
+                    .into_boxed()
+            }
+            // The reverse iteration starts from the pinned height,
+            // skipping blocks that are not part of the view yet.
+            IterDirection::Reverse => {
+                let height = height.unwrap_or(self.height);
+                self.chained_blocks(Some(height), direction)
+            }
+        }
+    }
+
+    fn latest_height(&self) -> StorageResult<BlockHeight> {
+        Ok(self.height)
+    }
+
+    fn latest_genesis_height(&self) -> StorageResult<BlockHeight> {
+        self.genesis_height()
+    }
+
+    fn first_block_height(&self) -> StorageResult<BlockHeight> {
+        match self
+            .off_chain_blocks(None, IterDirection::Forward)
+            .next()
+            .transpose()?
+        {
+            Some(block) => Ok(*block.header().height()),
+            None => self.genesis_height(),
+        }
+    }
+}
+
+impl<M> StorageInspect<M> for ReadView
+where
+    M: Mappable,
+    dyn OnChainDatabase: StorageInspect<M, Error = StorageError>,
+{
+    type Error = StorageError;
+
+    fn get(&self, key: &M::Key) -> StorageResult<Option<Cow<M::OwnedValue>>> {
+        self.retry(|| self.on_chain.get(key))
+    }
+
+    fn contains_key(&self, key: &M::Key) -> StorageResult<bool> {
+        self.retry(|| self.on_chain.contains_key(key))
+    }
+}
+
+// The off-chain tables can't be covered by a second generic implementation,
+// because it would overlap with the on-chain one above.
+impl StorageInspect<TransactionStatuses> for ReadView {
+    type Error = StorageError;
+
+    fn get(
+        &self,
+        key: &TxId,
+    ) -> StorageResult<Option<Cow<<TransactionStatuses as Mappable>::OwnedValue>>> {
+        self.retry(|| self.off_chain.get(key))
+    }
+
+    fn contains_key(&self, key: &TxId) -> StorageResult<bool> {
+        self.retry(|| self.off_chain.contains_key(key))
+    }
+}
+
+impl DatabaseCoins for ReadView {
+    fn all_coins(
+        &self,
+        start: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(UtxoId, CompressedCoin)>> {
+        self.retry_iter(|| self.on_chain.all_coins(start, direction))
+    }
+}
+
+impl DatabaseMessages for ReadView {
+    fn all_messages(
+        &self,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Message>> {
+        self.retry_iter(|| self.on_chain.all_messages(start_message_id, direction))
+    }
+
+    fn unspent_messages(
+        &self,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Message>> {
+        self.retry_iter(|| self.on_chain.unspent_messages(start_message_id, direction))
+    }
+
+    fn message_by_nonce(&self, nonce: &Nonce) -> StorageResult<Option<Message>> {
+        self.retry(|| self.on_chain.message_by_nonce(nonce))
+    }
+
+    fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
+        self.retry(|| self.on_chain.message_is_spent(nonce))
+    }
+
+    fn message_exists(&self, nonce: &Nonce) -> StorageResult<bool> {
+        self.retry(|| self.on_chain.message_exists(nonce))
+    }
+
+    fn message_status(&self, nonce: &Nonce) -> StorageResult<MessageStatus> {
+        self.retry(|| self.on_chain.message_status(nonce))
+    }
+
+    fn messages_spent_status(&self, nonces: &[Nonce]) -> StorageResult<Vec<bool>> {
+        self.retry(|| self.on_chain.messages_spent_status(nonces))
+    }
+}
+
+impl DatabaseRelayedTransactions for ReadView {
+    fn transaction_status(
+        &self,
+        id: Bytes32,
+    ) -> StorageResult<Option<RelayedTransactionStatus>> {
+        let maybe_status = self.retry(|| self.off_chain.relayed_tx_status(id))?;
+        Ok(maybe_status)
+    }
+
+    fn relayed_tx_status_history(
+        &self,
+        id: Bytes32,
+    ) -> BoxedIter<'_, StorageResult<RelayedTransactionStatus>> {
+        self.retry_iter(|| self.off_chain.relayed_tx_status_history(id))
+    }
+
+    fn relayed_tx_statuses(
+        &self,
+        start: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Bytes32, RelayedTransactionStatus)>> {
+        self.retry_iter(|| self.off_chain.relayed_tx_statuses(start, direction))
+    }
+}
+
+impl DatabaseContracts for ReadView {
+    fn contract_exists(&self, contract_id: &ContractId) -> StorageResult<bool> {
+        self.retry(|| self.on_chain.contract_exists(contract_id))
+    }
+
+    fn contract_balances(
+        &self,
+        contract: ContractId,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractBalance>> {
+        self.retry_iter(|| {
+            self.on_chain
+                .contract_balances(contract, start_asset, direction)
+        })
+    }
+
+    fn contract_balance_count(&self, contract: ContractId) -> StorageResult<u64> {
+        self.retry(|| self.on_chain.contract_balance_count(contract))
+    }
+
+    fn contract_asset_balance(
+        &self,
+        contract: ContractId,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<u64>> {
+        self.retry(|| self.on_chain.contract_asset_balance(contract, asset_id))
+    }
+
+    fn contract_state(
+        &self,
+        contract: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        self.retry(|| self.on_chain.contract_state(contract, key))
+    }
+
+    fn contract_state_root(&self, contract: ContractId) -> StorageResult<Bytes32> {
+        self.retry(|| self.on_chain.contract_state_root(contract))
+    }
+
+    fn contract_deployed_height(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<Option<BlockHeight>> {
+        self.retry(|| self.on_chain.contract_deployed_height(contract_id))
+    }
+}
+
+impl DatabaseChain for ReadView {
+    /// Returns the DA height of the latest on-chain block.
+    ///
+    /// Until the on-chain database has a block, the DA height of the last block
+    /// from before the regenesis is returned instead. The value may be stale
+    /// during the initialization of the node.
+    fn da_height(&self) -> StorageResult<DaBlockHeight> {
+        match self.retry(|| self.on_chain.da_height()) {
+            Err(StorageError::NotFound(_, _)) => self
+                .retry_iter(|| self.off_chain.old_blocks(None, IterDirection::Reverse))
+                .next()
+                .transpose()?
+                .map(|block| block.header().da_height)
+                .ok_or(not_found!("DaBlockHeight")),
+            result => result,
+        }
+    }
+
+    /// The sizes are reported for the whole database, not only for the data
+    /// at the pinned height of the view.
+    fn table_sizes(&self) -> StorageResult<Vec<(&'static str, u64)>> {
+        self.retry(|| self.on_chain.table_sizes())
+    }
+}
+
+impl DatabaseMessageProof for ReadView {
+    fn max_message_data_size(&self) -> Option<usize> {
+        self.config.max_message_data_size
+    }
+
+    fn block_history_proof(
+        &self,
+        message_block_height: &BlockHeight,
+        commit_block_height: &BlockHeight,
+    ) -> StorageResult<MerkleProof> {
+        // Only the commit blocks visible in the view are cached, so the view never
+        // returns a proof against a block above its pinned height.
+        let cacheable = *commit_block_height <= self.height;
+        if cacheable {
+            if let Some(proof) = self
+                .message_proofs
+                .get(message_block_height, commit_block_height)
+            {
+                return Ok(proof)
+            }
+        }
+
+        let proof = self.retry(|| {
+            self.on_chain
+                .block_history_proof(message_block_height, commit_block_height)
+        })?;
+        if cacheable {
+            self.message_proofs.insert(
+                *message_block_height,
+                *commit_block_height,
+                proof.clone(),
+                self.height,
+            );
+        }
+        Ok(proof)
+    }
+
+    fn block_history_root(
+        &self,
+        commit_block_height: &BlockHeight,
+    ) -> StorageResult<Bytes32> {
+        self.retry(|| self.on_chain.block_history_root(commit_block_height))
+    }
+
+    fn block_history_proofs<'a>(
+        &'a self,
+        requests: &'a [(BlockHeight, BlockHeight)],
+    ) -> BoxedIter<'a, StorageResult<MerkleProof>> {
+        self.retry_iter(|| self.on_chain.block_history_proofs(requests))
+    }
+
+    fn block_history_proofs_for_commit(
+        &self,
+        commit_block_height: &BlockHeight,
+        message_block_heights: &[BlockHeight],
+    ) -> StorageResult<Vec<MerkleProof>> {
+        self.retry(|| {
+            self.on_chain.block_history_proofs_for_commit(
+                commit_block_height,
+                message_block_heights,
+            )
+        })
+    }
+}
+
+impl OnChainDatabase for ReadView {}
+
+impl OffChainDatabase for ReadView {
+    fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight> {
+        self.retry(|| self.off_chain.block_height(block_id))
+    }
+
+    fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
+        let status = self.retry(|| self.off_chain.tx_status(tx_id))?;
+        if let TransactionStatus::Success { block_height, .. }
+        | TransactionStatus::Failed { block_height, .. } = &status
+        {
+            self.check_off_chain_height("tx_status", *block_height);
+        }
+        Ok(status)
+    }
+
+    fn owned_coins_ids(
+        &self,
+        owner: &Address,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>> {
+        self.retry_iter(|| self.off_chain.owned_coins_ids(owner, start_coin, direction))
+    }
+
+    fn owned_message_ids(
+        &self,
+        owner: &Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Nonce>> {
+        self.retry_iter(|| {
+            self.off_chain
+                .owned_message_ids(owner, start_message_id, direction)
+        })
+    }
+
+    fn owned_message_count(&self, owner: &Address) -> StorageResult<u64> {
+        self.retry(|| self.off_chain.owned_message_count(owner))
+    }
+
+    fn owned_transactions_ids(
+        &self,
+        owner: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        self.retry_iter(|| {
+            self.off_chain
+                .owned_transactions_ids(owner, start, direction)
+        })
+        .inspect(|result| {
+            if let Ok((tx_pointer, _)) = result {
+                self.check_off_chain_height(
+                    "owned_transactions_ids",
+                    tx_pointer.block_height(),
+                );
+            }
+        })
+        .into_boxed()
+    }
+
+    fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
+        self.retry(|| self.off_chain.contract_salt(contract_id))
+    }
+
+    fn contract_salts(
+        &self,
+        contract_ids: &[ContractId],
+    ) -> BoxedIter<'_, StorageResult<(ContractId, Salt)>> {
+        self.retry_iter(|| self.off_chain.contract_salts(contract_ids))
+    }
+
+    fn old_blocks(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<CompressedBlock>> {
+        self.retry_iter(|| self.off_chain.old_blocks(height, direction))
+    }
+
+    fn old_block_exists(&self, height: BlockHeight) -> StorageResult<bool> {
+        self.retry(|| self.off_chain.old_block_exists(height))
+    }
+
+    fn old_block_consensus(&self, height: BlockHeight) -> StorageResult<Consensus> {
+        self.retry(|| self.off_chain.old_block_consensus(height))
+            .map_err(|error| self.pre_regenesis_error(error))
+    }
+
+    fn old_transaction(
+        &self,
+        id: &TxId,
+    ) -> StorageResult<Option<fuel_core_types::fuel_tx::Transaction>> {
+        match self.retry(|| self.off_chain.old_transaction(id))? {
+            Some(transaction) => Ok(Some(transaction)),
+            None if self.has_pre_regenesis_data()? => Ok(None),
+            None => Err(ReadViewError::PreRegenesisDataUnavailable.into()),
+        }
+    }
+
+    fn old_transaction_at(
+        &self,
+        height: BlockHeight,
+        tx_index: u16,
+    ) -> StorageResult<Option<fuel_core_types::fuel_tx::Transaction>> {
+        self.retry(|| self.off_chain.old_transaction_at(height, tx_index))
+            .map_err(|error| self.pre_regenesis_error(error))
+    }
+
+    fn relayed_tx_status(
+        &self,
+        id: Bytes32,
+    ) -> StorageResult<Option<RelayedTransactionStatus>> {
+        self.retry(|| self.off_chain.relayed_tx_status(id))
+    }
+
+    fn relayed_tx_status_history(
+        &self,
+        id: Bytes32,
+    ) -> BoxedIter<'_, StorageResult<RelayedTransactionStatus>> {
+        self.retry_iter(|| self.off_chain.relayed_tx_status_history(id))
+    }
+
+    fn relayed_tx_statuses(
+        &self,
+        start: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Bytes32, RelayedTransactionStatus)>> {
+        self.retry_iter(|| self.off_chain.relayed_tx_statuses(start, direction))
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::{
+            database_description::off_chain::OffChain,
+            Database,
+        },
+        graphql_api::storage::{
+            blocks::FuelBlockIdsToHeights,
+            coins::{
+                owner_coin_id_key,
+                OwnedCoins,
+            },
+            contracts::ContractsInfo,
+            messages::{
+                OwnedMessageIds,
+                OwnedMessageKey,
+            },
+            old::{
+                OldFuelBlockConsensus,
+                OldFuelBlocks,
+                OldTransactions,
+            },
+            relayed_transactions::RelayedTransactionStatuses,
+            transactions::{
+                OwnedTransactionIndexKey,
+                OwnedTransactions,
+                OwnedTxCursor,
+            },
+        },
+        query::TransactionQueryData,
+    };
+    use fuel_core_storage::{
+        kv_store::KeyValueMutate,
+        structured_storage::TableWithBlueprint,
+        tables::{
+            ContractsAssets,
+            ContractsLatestUtxo,
+            SpentMessages,
+        },
+        transactional::WriteTransaction,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ApplicationHeader,
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        entities::contract::{
+            ContractUtxoInfo,
+            ContractsInfoType,
+        },
+        fuel_tx::{
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+    };
+    use std::{
+        ops::Range,
+        sync::atomic::AtomicUsize,
+    };
+
+    fn block(height: u32) -> CompressedBlock {
+        block_with_transactions(height, vec![])
+    }
+
+    fn block_with_transactions(
+        height: u32,
+        transactions: Vec<Transaction>,
+    ) -> CompressedBlock {
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                height: height.into(),
+                ..Default::default()
+            },
+        };
+        PartialFuelBlock::new(header, transactions)
+            .generate(&[], Default::default())
+            .compress(&ChainId::default())
+    }
+
+    /// Creates a database with `old_blocks` before regenesis in the off-chain database
+    /// and `new_blocks` after regenesis in the on-chain database.
+    fn read_database(old_blocks: Range<u32>, new_blocks: Range<u32>) -> ReadDatabase {
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+
+        for height in old_blocks {
+            off_chain
+                .storage_as_mut::<OldFuelBlocks>()
+                .insert(&height.into(), &block(height))
+                .unwrap();
+        }
+        for height in new_blocks {
+            on_chain
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height.into(), &block(height))
+                .unwrap();
+        }
+
+        ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+    }
+
+    fn heights(blocks: BoxedIter<'_, StorageResult<CompressedBlock>>) -> Vec<u32> {
+        blocks
+            .map(|block| (*block.unwrap().header().height()).into())
+            .collect()
+    }
+
+    #[test]
+    fn block_stream_plan__covers_heights_around_genesis() {
+        // Given
+        let genesis = Some(BlockHeight::from(5));
+        let cases = [
+            (Some(4u32), IterDirection::Forward, StreamPlan::OffThenOn),
+            (Some(4), IterDirection::Reverse, StreamPlan::OffChainOnly),
+            (Some(5), IterDirection::Forward, StreamPlan::OnChainOnly),
+            (Some(5), IterDirection::Reverse, StreamPlan::OnThenOff),
+            (Some(6), IterDirection::Forward, StreamPlan::OnChainOnly),
+            (Some(6), IterDirection::Reverse, StreamPlan::OnThenOff),
+            (None, IterDirection::Forward, StreamPlan::OffThenOn),
+            (None, IterDirection::Reverse, StreamPlan::OnThenOff),
+        ];
+
+        for (height, direction, expected) in cases {
+            // When
+            let plan =
+                block_stream_plan(height.map(BlockHeight::from), genesis, direction);
+
+            // Then
+            assert_eq!(plan, expected, "height {height:?}, direction {direction:?}");
+        }
+    }
+
+    #[test]
+    fn block_stream_plan__reads_both_databases_without_genesis() {
+        for (direction, expected) in [
+            (IterDirection::Forward, StreamPlan::OffThenOn),
+            (IterDirection::Reverse, StreamPlan::OnThenOff),
+        ] {
+            // When
+            let plan = block_stream_plan(Some(3.into()), None, direction);
+
+            // Then
+            assert_eq!(plan, expected);
+        }
+    }
+
+    #[test]
+    fn blocks_in_range__range_before_regenesis() {
+        // Given
+        let view = read_database(0..5, 5..10).view().unwrap();
+
+        // When
+        let forward = view.blocks_in_range(1.into(), 4.into(), IterDirection::Forward);
+        let reverse = view.blocks_in_range(3.into(), 0.into(), IterDirection::Reverse);
+
+        // Then
+        assert_eq!(heights(forward), vec![1, 2, 3]);
+        assert_eq!(heights(reverse), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn blocks_in_range__range_straddles_regenesis() {
+        // Given
+        let view = read_database(0..5, 5..10).view().unwrap();
+
+        // When
+        let forward = view.blocks_in_range(3.into(), 7.into(), IterDirection::Forward);
+        let reverse = view.blocks_in_range(7.into(), 3.into(), IterDirection::Reverse);
+
+        // Then
+        assert_eq!(heights(forward), vec![3, 4, 5, 6]);
+        assert_eq!(heights(reverse), vec![7, 6, 5, 4]);
+    }
+
+    #[test]
+    fn block_headers__span_regenesis_like_blocks() {
+        // Given
+        let view = read_database(0..5, 5..10).view().unwrap();
+        let header_heights = |height: Option<u32>, direction| {
+            view.block_headers(height.map(Into::into), direction)
+                .map(|header| u32::from(*header.unwrap().height()))
+                .collect::<Vec<_>>()
+        };
+
+        // When
+        let forward = header_heights(Some(3), IterDirection::Forward);
+        let reverse = header_heights(Some(6), IterDirection::Reverse);
+
+        // Then
+        assert_eq!(
+            forward,
+            heights(view.blocks(Some(3.into()), IterDirection::Forward))
+        );
+        assert_eq!(forward, vec![3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(reverse, vec![6, 5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn blocks_in_range__empty_range() {
+        // Given
+        let view = read_database(0..5, 5..10).view().unwrap();
+
+        // When
+        let forward = view.blocks_in_range(5.into(), 5.into(), IterDirection::Forward);
+        let reverse = view.blocks_in_range(5.into(), 5.into(), IterDirection::Reverse);
+
+        // Then
+        assert_eq!(heights(forward), Vec::<u32>::new());
+        assert_eq!(heights(reverse), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn owned_coins_ids_multi__merges_owners_in_utxo_id_order() {
+        // Given
+        let first_owner = Address::from([1; 32]);
+        let second_owner = Address::from([2; 32]);
+        let coins = [
+            (first_owner, UtxoId::new(TxId::from([3; 32]), 0)),
+            (second_owner, UtxoId::new(TxId::from([1; 32]), 1)),
+            (first_owner, UtxoId::new(TxId::from([1; 32]), 0)),
+            (second_owner, UtxoId::new(TxId::from([2; 32]), 0)),
+            (Address::from([3; 32]), UtxoId::new(TxId::from([0; 32]), 0)),
+        ];
+        let mut off_chain = Database::<OffChain>::default();
+        for (owner, utxo_id) in coins.iter() {
+            off_chain
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(owner, utxo_id), &())
+                .unwrap();
+        }
+        let owners = [first_owner, second_owner];
+
+        // When
+        let forward = off_chain
+            .owned_coins_ids_multi(&owners, None, IterDirection::Forward)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+        let reverse = off_chain
+            .owned_coins_ids_multi(&owners, None, IterDirection::Reverse)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        let mut expected = vec![coins[2], coins[1], coins[3], coins[0]];
+        assert_eq!(forward, expected);
+        expected.reverse();
+        assert_eq!(reverse, expected);
+    }
+
+    #[test]
+    fn owned_coins_ids_page__returns_pages_with_continuation_cursor() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let utxo_ids: Vec<_> = (0..5u8)
+            .map(|i| UtxoId::new(TxId::from([i; 32]), 0))
+            .collect();
+        let mut off_chain = Database::<OffChain>::default();
+        for utxo_id in utxo_ids.iter() {
+            off_chain
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(&owner, utxo_id), &())
+                .unwrap();
+        }
+        let page = |after: Option<UtxoId>, direction: IterDirection| {
+            off_chain
+                .owned_coins_ids_page(&owner, after, direction, 2)
+                .unwrap()
+        };
+
+        // When
+        let first = page(None, IterDirection::Forward);
+        let second = page(first.1, IterDirection::Forward);
+        let last = page(second.1, IterDirection::Forward);
+        let reverse = page(Some(utxo_ids[3]), IterDirection::Reverse);
+
+        // Then
+        assert_eq!(first, (utxo_ids[0..2].to_vec(), Some(utxo_ids[1])));
+        assert_eq!(second, (utxo_ids[2..4].to_vec(), Some(utxo_ids[3])));
+        assert_eq!(last, (utxo_ids[4..].to_vec(), None));
+        assert_eq!(reverse, (vec![utxo_ids[2], utxo_ids[1]], Some(utxo_ids[1])));
+    }
+
+    fn owned_transactions_database(owner: &Address) -> Database<OffChain> {
+        let mut off_chain = Database::<OffChain>::default();
+        for height in 1..4u32 {
+            for tx_idx in 0..2 {
+                off_chain
+                    .storage_as_mut::<OwnedTransactions>()
+                    .insert(
+                        &OwnedTransactionIndexKey::new(owner, height.into(), tx_idx),
+                        &TxId::default(),
+                    )
+                    .unwrap();
+            }
+        }
+        off_chain
+    }
+
+    fn tx_pointers(
+        transactions: BoxedIter<'_, StorageResult<(TxPointer, TxId)>>,
+    ) -> Vec<(u32, u16)> {
+        transactions
+            .map(|result| {
+                let (tx_pointer, _) = result.unwrap();
+                (tx_pointer.block_height().into(), tx_pointer.tx_index())
+            })
+            .collect()
+    }
+
+    fn contract_balances_database(contract: &ContractId) -> Database {
+        let mut on_chain = Database::default();
+        for asset in [1u8, 3, 5] {
+            on_chain
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&(contract, &AssetId::from([asset; 32])).into(), &100)
+                .unwrap();
+        }
+        // The balance of another contract shouldn't leak into the iteration.
+        on_chain
+            .storage_as_mut::<ContractsAssets>()
+            .insert(
+                &(&ContractId::from([2; 32]), &AssetId::from([0; 32])).into(),
+                &100,
+            )
+            .unwrap();
+        on_chain
+    }
+
+    fn assets(balances: BoxedIter<'_, StorageResult<ContractBalance>>) -> Vec<u8> {
+        balances.map(|result| result.unwrap().asset_id[0]).collect()
+    }
+
+    #[test]
+    fn contract_balances__forward_cursor_is_inclusive() {
+        // Given
+        let contract = ContractId::from([1; 32]);
+        let on_chain = contract_balances_database(&contract);
+        let balances = |start: Option<u8>| {
+            assets(on_chain.contract_balances(
+                contract,
+                start.map(|asset| AssetId::from([asset; 32])),
+                IterDirection::Forward,
+            ))
+        };
+
+        // When
+        let all = balances(None);
+        let from_existing = balances(Some(3));
+        let from_missing = balances(Some(4));
+        let from_last = balances(Some(5));
+
+        // Then
+        assert_eq!(all, vec![1, 3, 5]);
+        assert_eq!(from_existing, vec![3, 5]);
+        assert_eq!(from_missing, vec![5]);
+        assert_eq!(from_last, vec![5]);
+    }
+
+    #[test]
+    fn contract_balances__reverse_cursor_is_exclusive() {
+        // Given
+        let contract = ContractId::from([1; 32]);
+        let on_chain = contract_balances_database(&contract);
+        let balances = |start: Option<u8>| {
+            assets(on_chain.contract_balances(
+                contract,
+                start.map(|asset| AssetId::from([asset; 32])),
+                IterDirection::Reverse,
+            ))
+        };
+
+        // When
+        let all = balances(None);
+        let before_existing = balances(Some(3));
+        let before_missing = balances(Some(4));
+        let before_last = balances(Some(5));
+        let before_first = balances(Some(1));
+
+        // Then
+        assert_eq!(all, vec![5, 3, 1]);
+        assert_eq!(before_existing, vec![1]);
+        assert_eq!(before_missing, vec![3, 1]);
+        assert_eq!(before_last, vec![3, 1]);
+        assert_eq!(before_first, Vec::<u8>::new());
+    }
+
+    #[test]
+    fn contract_balances_page__returns_the_cursor_of_the_next_page() {
+        // Given
+        let contract = ContractId::from([1; 32]);
+        let on_chain = contract_balances_database(&contract);
+        let page = |start: Option<AssetId>, limit| {
+            let (balances, next) = on_chain
+                .contract_balances_page(contract, start, limit)
+                .unwrap();
+            let assets: Vec<u8> =
+                balances.iter().map(|balance| balance.asset_id[0]).collect();
+            (assets, next)
+        };
+
+        // When
+        let (first, cursor) = page(None, 2);
+        let (second, end) = page(cursor, 2);
+        let (all, all_end) = page(None, 3);
+
+        // Then
+        assert_eq!(first, vec![1, 3]);
+        assert_eq!(cursor, Some(AssetId::from([5; 32])));
+        assert_eq!(second, vec![5]);
+        assert_eq!(end, None);
+        assert_eq!(all, vec![1, 3, 5]);
+        assert_eq!(all_end, None);
+    }
+
+    #[test]
+    fn owned_transactions__joins_bodies_and_skips_missing_ones() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let tx = Transaction::default_test_tx();
+        let old_tx_id = TxId::from([1; 32]);
+        let missing_tx_id = TxId::from([2; 32]);
+        let new_tx_id = TxId::from([3; 32]);
+
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&3.into(), &block(3))
+            .unwrap();
+        on_chain
+            .storage_as_mut::<Transactions>()
+            .insert(&new_tx_id, &tx)
+            .unwrap();
+        off_chain
+            .storage_as_mut::<OldTransactions>()
+            .insert(&old_tx_id, &tx)
+            .unwrap();
+        for (height, tx_id) in [(1u32, old_tx_id), (2, missing_tx_id), (3, new_tx_id)] {
+            off_chain
+                .storage_as_mut::<OwnedTransactions>()
+                .insert(
+                    &OwnedTransactionIndexKey::new(&owner, height.into(), 0),
+                    &tx_id,
+                )
+                .unwrap();
+        }
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view()
+            .unwrap();
+
+        // When
+        let transactions = view
+            .owned_transactions(owner, None, IterDirection::Forward)
+            .map(|result| {
+                let (tx_pointer, tx) = result.unwrap();
+                (u32::from(tx_pointer.block_height()), tx)
+            })
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(transactions, vec![(1, tx.clone()), (3, tx)]);
+    }
+
+    #[test]
+    fn storage_inspect__reads_transaction_statuses_from_off_chain() {
+        // Given
+        let tx_id = TxId::from([1; 32]);
+        let unknown_tx_id = TxId::from([2; 32]);
+        let status = TransactionStatus::Submitted {
+            time: Default::default(),
+        };
+        let mut off_chain = Database::<OffChain>::default();
+        off_chain
+            .storage_as_mut::<TransactionStatuses>()
+            .insert(&tx_id, &status)
+            .unwrap();
+        let view =
+            ReadDatabase::new(Database::default(), off_chain, ReadViewConfig::default())
+                .view_at(0.into())
+                .unwrap();
+
+        // When
+        let stored = view.storage::<TransactionStatuses>().get(&tx_id).unwrap();
+        let unknown = view
+            .storage::<TransactionStatuses>()
+            .contains_key(&unknown_tx_id)
+            .unwrap();
+
+        // Then
+        assert_eq!(stored.map(Cow::into_owned), Some(status));
+        assert!(!unknown);
+    }
+
+    #[test]
+    fn tx_status_detail__marks_only_submitted_transactions_as_pending() {
+        // Given
+        let submitted_tx_id = TxId::from([1; 32]);
+        let executed_tx_id = TxId::from([2; 32]);
+        let unknown_tx_id = TxId::from([3; 32]);
+        let submitted = TransactionStatus::Submitted {
+            time: Default::default(),
+        };
+        let executed = TransactionStatus::Success {
+            block_height: 1.into(),
+            time: Default::default(),
+            result: None,
+            receipts: vec![],
+            total_gas: 0,
+            total_fee: 0,
+        };
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&1.into(), &block(1))
+            .unwrap();
+        off_chain
+            .storage_as_mut::<TransactionStatuses>()
+            .insert(&submitted_tx_id, &submitted)
+            .unwrap();
+        off_chain
+            .storage_as_mut::<TransactionStatuses>()
+            .insert(&executed_tx_id, &executed)
+            .unwrap();
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view()
+            .unwrap();
+
+        // When
+        let submitted_detail = view.tx_status_detail(&submitted_tx_id).unwrap();
+        let executed_detail = view.tx_status_detail(&executed_tx_id).unwrap();
+        let unknown_detail = view.tx_status_detail(&unknown_tx_id).unwrap();
+
+        // Then
+        assert_eq!(submitted_detail, Some((submitted, false)));
+        assert_eq!(executed_detail, Some((executed, true)));
+        assert_eq!(unknown_detail, None);
+    }
+
+    #[test]
+    fn owned_transactions_ids__forward_cursor_on_existing_tx_pointer_is_inclusive() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let off_chain = owned_transactions_database(&owner);
+        let cursor = TxPointer::new(2.into(), 1);
+
+        // When
+        let page =
+            off_chain.owned_transactions_ids(owner, Some(cursor), IterDirection::Forward);
+
+        // Then
+        assert_eq!(tx_pointers(page), vec![(2, 1), (3, 0), (3, 1)]);
+    }
+
+    #[test]
+    fn owned_transactions_ids__reverse_cursor_on_existing_tx_pointer_is_exclusive() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let off_chain = owned_transactions_database(&owner);
+        let cursor = TxPointer::new(2.into(), 1);
+
+        // When
+        let page =
+            off_chain.owned_transactions_ids(owner, Some(cursor), IterDirection::Reverse);
+
+        // Then
+        assert_eq!(tx_pointers(page), vec![(2, 0), (1, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn owned_transactions_ids__reverse_cursor_between_tx_pointers() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let off_chain = owned_transactions_database(&owner);
+        let cursor = TxPointer::new(2.into(), 5);
+
+        // When
+        let page =
+            off_chain.owned_transactions_ids(owner, Some(cursor), IterDirection::Reverse);
+
+        // Then
+        assert_eq!(tx_pointers(page), vec![(2, 1), (2, 0), (1, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn owned_transactions_ids_from__cursor_is_exclusive_by_tx_id() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let off_chain = owned_transactions_database(&owner);
+        let tx_pointer = TxPointer::new(2.into(), 1);
+        let at_cursor = OwnedTxCursor {
+            tx_pointer,
+            tx_id: TxId::default(),
+        };
+        let after_cursor = OwnedTxCursor {
+            tx_pointer,
+            tx_id: TxId::from([0xff; 32]),
+        };
+        let page = |cursor, direction| {
+            tx_pointers(off_chain.owned_transactions_ids_from(
+                owner,
+                Some(cursor),
+                direction,
+            ))
+        };
+
+        // When
+        let forward = page(at_cursor, IterDirection::Forward);
+        let reverse = page(at_cursor, IterDirection::Reverse);
+        let forward_after_id = page(after_cursor, IterDirection::Forward);
+        let reverse_after_id = page(after_cursor, IterDirection::Reverse);
+
+        // Then
+        assert_eq!(forward, vec![(3, 0), (3, 1)]);
+        assert_eq!(reverse, vec![(2, 0), (1, 1), (1, 0)]);
+        assert_eq!(forward_after_id, vec![(3, 0), (3, 1)]);
+        assert_eq!(reverse_after_id, vec![(2, 1), (2, 0), (1, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn database_heights__returns_latest_heights_of_both_databases() {
+        // Given
+        let database = read_database(0..5, 5..10);
+
+        // When
+        let view = database.view().unwrap();
+
+        // Then
+        assert_eq!(view.database_heights(), (Some(9.into()), None));
+        assert_eq!(view.pinned_height(), 9.into());
+    }
+
+    #[test]
+    fn regenesis_boundary__is_the_genesis_height_only_with_older_blocks() {
+        // Given
+        let with_history = read_database(2..5, 5..10).view().unwrap();
+        let fresh_chain = read_database(0..0, 5..10).view().unwrap();
+        let only_seam_duplicate = read_database(5..6, 5..10).view().unwrap();
+
+        // When
+        let with_history = with_history.regenesis_boundary().unwrap();
+        let fresh_chain = fresh_chain.regenesis_boundary().unwrap();
+        let only_seam_duplicate = only_seam_duplicate.regenesis_boundary().unwrap();
+
+        // Then
+        assert_eq!(with_history, Some(5.into()));
+        assert_eq!(fresh_chain, None);
+        assert_eq!(only_seam_duplicate, None);
+    }
+
+    #[test]
+    fn old_block_consensus__distinguishes_missing_history_from_missing_block() {
+        // Given
+        let with_history = read_database(2..5, 5..10).view().unwrap();
+        let fresh_chain = read_database(0..0, 5..10).view().unwrap();
+
+        // When
+        let missing_block = with_history.old_block_consensus(1.into()).unwrap_err();
+        let missing_history = fresh_chain.old_block_consensus(1.into()).unwrap_err();
+        let missing_transaction =
+            fresh_chain.old_transaction(&TxId::zeroed()).unwrap_err();
+        let missing_transaction_at =
+            fresh_chain.old_transaction_at(1.into(), 0).unwrap_err();
+        let mut old_blocks = fresh_chain.old_blocks(None, IterDirection::Forward);
+
+        // Then
+        assert!(matches!(missing_block, StorageError::NotFound(_, _)));
+        assert_eq!(with_history.old_transaction(&TxId::zeroed()).unwrap(), None);
+        for error in [missing_history, missing_transaction, missing_transaction_at] {
+            let StorageError::Other(error) = error else {
+                panic!("Expected the history to be unavailable, got {error:?}");
+            };
+            assert!(matches!(
+                error.downcast_ref::<ReadViewError>(),
+                Some(ReadViewError::PreRegenesisDataUnavailable)
+            ));
+        }
+        assert!(old_blocks.next().is_none());
+    }
+
+    #[test]
+    fn heights__resolves_latest_genesis_and_da_heights_together() {
+        // Given
+        let database = read_database(0..5, 5..10);
+        let view = database.view_at(7.into()).unwrap();
+
+        // When
+        let heights = view.heights().unwrap();
+
+        // Then
+        assert_eq!(
+            heights,
+            Heights {
+                latest: 7.into(),
+                genesis: 5.into(),
+                da: 0.into(),
+            }
+        );
+    }
+
+    #[test]
+    fn blocks_by_heights__preserves_order_and_reports_missing_heights() {
+        // Given
+        let database = read_database(0..3, 5..10);
+        let view = database.view().unwrap();
+
+        // When
+        let blocks = view
+            .blocks_by_heights(&[7.into(), 1.into(), 4.into(), 12.into(), 5.into()])
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(blocks.len(), 5);
+        assert_eq!(blocks[0].as_ref().unwrap(), &block(7));
+        assert_eq!(blocks[1].as_ref().unwrap(), &block(1));
+        assert!(matches!(blocks[2], Err(StorageError::NotFound(_, _))));
+        let Err(StorageError::Other(unavailable)) = &blocks[3] else {
+            panic!("Expected the height to be unavailable, got {:?}", blocks[3]);
+        };
+        assert!(matches!(
+            unavailable.downcast_ref::<ReadViewError>(),
+            Some(ReadViewError::NotYetAvailable { .. })
+        ));
+        assert_eq!(blocks[4].as_ref().unwrap(), &block(5));
+    }
+
+    #[test]
+    fn messages_spent_status__preserves_the_order_of_nonces() {
+        // Given
+        let mut on_chain = Database::default();
+        for nonce in [Nonce::from([1; 32]), Nonce::from([3; 32])] {
+            on_chain
+                .storage_as_mut::<SpentMessages>()
+                .insert(&nonce, &())
+                .unwrap();
+        }
+        let view = ReadDatabase::new(
+            on_chain,
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        )
+        .view_at(0.into())
+        .unwrap();
+        let nonces = [3u8, 2, 1, 3].map(|i| Nonce::from([i; 32]));
+
+        // When
+        let statuses = view.messages_spent_status(&nonces).unwrap();
+        let empty = view.messages_spent_status(&[]).unwrap();
+
+        // Then
+        assert_eq!(statuses, vec![true, false, true, true]);
+        assert_eq!(empty, Vec::<bool>::new());
+    }
+
+    #[test]
+    fn has_block_after__checks_one_step_across_regenesis() {
+        // Given
+        let database = read_database(2..5, 5..10);
+        let view = database.view().unwrap();
+        let pinned_below_latest = database.view_at(7.into()).unwrap();
+        let after = |view: &ReadView, height: u32, direction| {
+            view.has_block_after(height.into(), direction).unwrap()
+        };
+
+        // When
+        let forward = [2, 4, 8, 9].map(|h| after(&view, h, IterDirection::Forward));
+        let reverse = [9, 5, 3, 2].map(|h| after(&view, h, IterDirection::Reverse));
+        let above_pinned = after(&pinned_below_latest, 7, IterDirection::Forward);
+
+        // Then
+        assert_eq!(forward, [true, true, true, false]);
+        assert_eq!(reverse, [true, true, true, false]);
+        assert!(!above_pinned);
+    }
+
+    #[test]
+    fn blocks_exist__checks_both_sides_of_regenesis_in_input_order() {
+        // Given
+        let view = read_database(2..5, 5..10).view_at(8.into()).unwrap();
+        let heights = [9, 6, 1, 3, 5, 4, 8].map(BlockHeight::from);
+
+        // When
+        let exist = view.blocks_exist(&heights).unwrap();
+
+        // Then
+        assert_eq!(exist, vec![false, true, false, true, true, true, true]);
+    }
+
+    #[test]
+    fn contracts_touched_in_block__are_derived_from_successful_receipts() {
+        // Given
+        let contract = |byte| ContractId::from([byte; 32]);
+        let asset_id = AssetId::from([9; 32]);
+        let transactions: Vec<Transaction> = (0..2u8)
+            .map(|seed| {
+                TransactionBuilder::script(vec![seed], vec![]).finalize_as_transaction()
+            })
+            .collect();
+        let executed = TransactionStatus::Success {
+            block_height: 1.into(),
+            time: Default::default(),
+            result: None,
+            receipts: vec![
+                Receipt::call(
+                    ContractId::zeroed(),
+                    contract(3),
+                    0,
+                    asset_id,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                ),
+                Receipt::transfer(contract(3), contract(1), 5, asset_id, 0, 0),
+                Receipt::mint(Default::default(), contract(3), 5, 0, 0),
+            ],
+            total_gas: 0,
+            total_fee: 0,
+        };
+        let reverted = TransactionStatus::Failed {
+            block_height: 1.into(),
+            time: Default::default(),
+            result: None,
+            receipts: vec![Receipt::call(
+                ContractId::zeroed(),
+                contract(7),
+                0,
+                asset_id,
+                0,
+                0,
+                0,
+                0,
+                0,
+            )],
+            total_gas: 0,
+            total_fee: 0,
+        };
+        let block = block_with_transactions(1, transactions);
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&1.into(), &block)
+            .unwrap();
+        for (tx_id, status) in block.transactions().iter().zip([executed, reverted]) {
+            off_chain
+                .storage_as_mut::<TransactionStatuses>()
+                .insert(tx_id, &status)
+                .unwrap();
+        }
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view_at(1.into())
+            .unwrap();
+
+        // When
+        let touched: Vec<_> = view
+            .contracts_touched_in_block(1.into())
+            .try_collect()
+            .unwrap();
+
+        // Then
+        assert_eq!(touched, vec![contract(1), contract(3)]);
+    }
+
+    #[test]
+    fn genesis_block__is_the_first_on_chain_block() {
+        // Given
+        let with_history = read_database(2..5, 5..10).view().unwrap();
+        let without_blocks = read_database(2..5, 0..0).view_at(4.into()).unwrap();
+
+        // When
+        let genesis_block = with_history.genesis_block().unwrap();
+        let missing = without_blocks.genesis_block().unwrap_err();
+
+        // Then
+        assert_eq!(genesis_block, block(5));
+        assert!(matches!(missing, StorageError::NotFound(_, _)));
+    }
+
+    #[test]
+    fn latest_blocks__are_descending_and_cross_regenesis() {
+        // Given
+        let view = read_database(2..5, 5..10).view().unwrap();
+
+        // When
+        let heights = |n| {
+            view.latest_blocks(n)
+                .unwrap()
+                .iter()
+                .map(|block| u32::from(*block.header().height()))
+                .collect::<Vec<_>>()
+        };
+        let recent = heights(3);
+        let crossing = heights(6);
+        let whole_chain = heights(100);
+        let none = heights(0);
+
+        // Then
+        assert_eq!(recent, vec![9, 8, 7]);
+        assert_eq!(crossing, vec![9, 8, 7, 6, 5, 4]);
+        assert_eq!(whole_chain, vec![9, 8, 7, 6, 5, 4, 3, 2]);
+        assert_eq!(none, Vec::<u32>::new());
+    }
+
+    #[test]
+    fn block_transaction_count__counts_on_both_sides_of_regenesis() {
+        // Given
+        let transactions = |count| vec![Transaction::default_test_tx(); count];
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        off_chain
+            .storage_as_mut::<OldFuelBlocks>()
+            .insert(&1.into(), &block_with_transactions(1, transactions(2)))
+            .unwrap();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&2.into(), &block_with_transactions(2, transactions(3)))
+            .unwrap();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&3.into(), &block(3))
+            .unwrap();
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view()
+            .unwrap();
+
+        // When
+        let old_block = view.block_transaction_count(1.into()).unwrap();
+        let new_block = view.block_transaction_count(2.into()).unwrap();
+        let empty_block = view.block_transaction_count(3.into()).unwrap();
+        let unknown = view.block_transaction_count(0.into());
+
+        // Then
+        assert_eq!(old_block, 2);
+        assert_eq!(new_block, 3);
+        assert_eq!(empty_block, 0);
+        assert!(matches!(unknown, Err(StorageError::NotFound(_, _))));
+    }
+
+    #[tokio::test]
+    async fn block_history_proofs_concurrent__match_single_proofs_in_order() {
+        // Given
+        let view = read_database(0..0, 0..10).view().unwrap();
+        let requests = [
+            (BlockHeight::from(3), BlockHeight::from(7)),
+            (BlockHeight::from(0), BlockHeight::from(9)),
+            (BlockHeight::from(5), BlockHeight::from(5)),
+            (BlockHeight::from(1), BlockHeight::from(2)),
+        ];
+
+        // When
+        let proofs = view
+            .block_history_proofs_concurrent(&requests, 2)
+            .await
+            .unwrap();
+
+        // Then
+        let expected = requests
+            .iter()
+            .map(|(message, commit)| view.block_history_proof(message, commit).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(proofs, expected);
+    }
+
+    #[tokio::test]
+    async fn block_history_proofs_concurrent__fails_on_the_first_error() {
+        // Given
+        let view = read_database(0..0, 0..10).view().unwrap();
+        let requests = [
+            (BlockHeight::from(3), BlockHeight::from(7)),
+            (BlockHeight::from(8), BlockHeight::from(2)),
+            (BlockHeight::from(1), BlockHeight::from(2)),
+        ];
+
+        // When
+        let result = view.block_history_proofs_concurrent(&requests, 1).await;
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("higher than `commit_block_height`"), "{err}");
+    }
+
+    #[test]
+    fn block_consensus__resolves_consensus_on_both_sides_of_regenesis() {
+        // Given
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let old_consensus = Consensus::Genesis(Default::default());
+        let new_consensus = Consensus::PoA(Default::default());
+        off_chain
+            .storage_as_mut::<OldFuelBlocks>()
+            .insert(&4.into(), &block(4))
+            .unwrap();
+        off_chain
+            .storage_as_mut::<OldFuelBlockConsensus>()
+            .insert(&4.into(), &old_consensus)
+            .unwrap();
+        for height in 5..7u32 {
+            on_chain
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height.into(), &block(height))
+                .unwrap();
+            on_chain
+                .storage_as_mut::<SealedBlockConsensus>()
+                .insert(&height.into(), &new_consensus)
+                .unwrap();
+        }
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view_at(6.into())
+            .unwrap();
+
+        // When
+        let old = view.block_consensus(4.into()).unwrap();
+        let new = view.block_consensus(6.into()).unwrap();
+        let unavailable = view.block_consensus(7.into()).unwrap_err();
+
+        // Then
+        assert_eq!(old, old_consensus);
+        assert_eq!(new, new_consensus);
+        let StorageError::Other(unavailable) = unavailable else {
+            panic!("Expected the height to be unavailable, got {unavailable:?}");
+        };
+        assert!(matches!(
+            unavailable.downcast_ref::<ReadViewError>(),
+            Some(ReadViewError::NotYetAvailable { .. })
+        ));
+    }
+
+    #[test]
+    fn block_by_id__resolves_blocks_on_both_sides_of_regenesis() {
+        // Given
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        for height in 0..5 {
+            off_chain
+                .storage_as_mut::<OldFuelBlocks>()
+                .insert(&height.into(), &block(height))
+                .unwrap();
+        }
+        for height in 5..10 {
+            on_chain
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height.into(), &block(height))
+                .unwrap();
+        }
+        for height in [2, 7] {
+            off_chain
+                .storage_as_mut::<FuelBlockIdsToHeights>()
+                .insert(&block(height).header().id(), &height.into())
+                .unwrap();
+        }
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view_at(7.into())
+            .unwrap();
+
+        // When
+        let old_block = view.block_by_id(&block(2).header().id()).unwrap();
+        let new_block = view.block_by_id(&block(7).header().id()).unwrap();
+        let unknown = view.block_by_id(&block(3).header().id()).unwrap();
+
+        // Then
+        assert_eq!(old_block, Some(block(2)));
+        assert_eq!(new_block, Some(block(7)));
+        assert_eq!(unknown, None);
+    }
+
+    #[test]
+    fn readiness_check__requires_the_genesis_block_in_both_databases() {
+        // Given
+        let mut on_chain = Database::default();
+        let mut indexed_off_chain = Database::<OffChain>::default();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&5.into(), &block(5))
+            .unwrap();
+        indexed_off_chain
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&block(5).header().id(), &5.into())
+            .unwrap();
+        let ready = ReadDatabase::new(
+            on_chain.clone(),
+            indexed_off_chain,
+            ReadViewConfig::default(),
+        );
+        let not_indexed = ReadDatabase::new(
+            on_chain,
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        );
+
+        // When
+        let ready = ready.readiness_check();
+        let not_indexed = not_indexed.readiness_check();
+
+        // Then
+        assert!(ready.is_ok());
+        assert!(matches!(not_indexed, Err(StorageError::NotFound(_, _))));
+    }
+
+    #[test]
+    fn consistent_view__returns_the_last_view_if_heights_diverge() {
+        // Given
+        let database = read_database(0..5, 5..10);
+
+        // When
+        let view = database.consistent_view(3).unwrap();
+
+        // Then
+        assert!(!view.is_consistent());
+        assert_eq!(view.pinned_height(), 9.into());
+    }
+
+    #[test]
+    fn check_consistency__warns_only_when_enabled() {
+        // Given
+        let view = |check_consistency| {
+            let mut on_chain = Database::default();
+            for height in 0..2 {
+                on_chain
+                    .storage_as_mut::<FuelBlocks>()
+                    .insert(&height.into(), &block(height))
+                    .unwrap();
+            }
+            let config = ReadViewConfig {
+                check_consistency,
+                ..Default::default()
+            };
+            ReadDatabase::new(on_chain, Database::<OffChain>::default(), config)
+                .view()
+                .unwrap()
+        };
+        let checked = view(true);
+        let unchecked = view(false);
+
+        // When
+        let diverged = checked.check_consistency();
+        let above_pinned = checked.check_off_chain_height("tx_status", 2.into());
+        let at_pinned = checked.check_off_chain_height("tx_status", 1.into());
+        let unchecked_diverged = unchecked.check_consistency();
+        let unchecked_above_pinned =
+            unchecked.check_off_chain_height("tx_status", 2.into());
+
+        // Then
+        assert!(!checked.is_consistent());
+        assert!(diverged);
+        assert!(above_pinned);
+        assert!(!at_pinned);
+        assert!(!unchecked_diverged);
+        assert!(!unchecked_above_pinned);
+    }
+
+    /// Creates a database with the latest on-chain height at `on_chain_height`
+    /// and the latest off-chain height at `off_chain_height`.
+    fn skewed_database(
+        on_chain_height: u32,
+        off_chain_height: u32,
+        max_height_skew: u32,
+    ) -> ReadDatabase {
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        for height in 0..=on_chain_height {
+            on_chain
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height.into(), &block(height))
+                .unwrap();
+        }
+        for height in 0..=off_chain_height {
+            off_chain
+                .storage_as_mut::<FuelBlockIdsToHeights>()
+                .insert(&block(height).header().id(), &height.into())
+                .unwrap();
+        }
+        let config = ReadViewConfig {
+            max_height_skew,
+            ..Default::default()
+        };
+        ReadDatabase::new(on_chain, off_chain, config)
+    }
+
+    #[test]
+    fn view__is_created_if_the_skew_is_within_the_tolerance() {
+        // Given
+        let below = skewed_database(5, 5, 1);
+        let at = skewed_database(5, 4, 1);
+        let at_custom = skewed_database(5, 2, 3);
+
+        // When
+        let below = below.view().unwrap();
+        let at = at.view().unwrap();
+        let at_custom = at_custom.view().unwrap();
+
+        // Then
+        assert_eq!(below.pinned_height(), 5.into());
+        assert_eq!(at.pinned_height(), 4.into());
+        assert_eq!(at_custom.pinned_height(), 2.into());
+    }
+
+    #[test]
+    fn view__fails_if_the_skew_exceeds_the_tolerance() {
+        // Given
+        let database = skewed_database(5, 3, 1);
+
+        // When
+        let result = database.view();
+        let consistent = database.consistent_view(3);
+        let pinned = database.view_at(3.into());
+
+        // Then
+        let Err(StorageError::Other(error)) = result else {
+            panic!("Expected the skew error")
+        };
+        assert!(matches!(
+            error.downcast_ref::<ReadViewError>(),
+            Some(ReadViewError::ViewSkewExceeded { max_skew: 1, .. })
+        ));
+        assert!(consistent.is_err());
+        assert!(pinned.is_ok());
+    }
+
+    #[test]
+    fn read_view_config__tolerates_one_block_of_skew_by_default() {
+        assert_eq!(ReadViewConfig::default().max_height_skew, 1);
+    }
+
+    #[test]
+    fn consistent_view__is_consistent_if_heights_match() {
+        // Given
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&0.into(), &block(0))
+            .unwrap();
+        off_chain
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&Default::default(), &0.into())
+            .unwrap();
+        let database = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default());
+
+        // When
+        let view = database.consistent_view(0).unwrap();
+
+        // Then
+        assert!(view.is_consistent());
+        assert_eq!(view.database_heights(), (Some(0.into()), Some(0.into())));
+    }
+
+    #[test]
+    fn da_height__falls_back_to_the_last_old_block() {
+        // Given
+        let mut off_chain = Database::<OffChain>::default();
+        let header = PartialBlockHeader {
+            application: ApplicationHeader {
+                da_height: 7.into(),
+                ..Default::default()
+            },
+            consensus: ConsensusHeader::<Empty> {
+                height: 4.into(),
+                ..Default::default()
+            },
+        };
+        let old_block = PartialFuelBlock::new(header, vec![])
+            .generate(&[], Default::default())
+            .compress(&ChainId::default());
+        off_chain
+            .storage_as_mut::<OldFuelBlocks>()
+            .insert(&4.into(), &old_block)
+            .unwrap();
+        let database =
+            ReadDatabase::new(Database::default(), off_chain, ReadViewConfig::default());
+        let view = database.view_at(4.into()).unwrap();
+
+        // When
+        let da_height = view.da_height().unwrap();
+
+        // Then
+        assert_eq!(da_height, 7.into());
+    }
+
+    fn retrying_view(max_retries: usize) -> ReadView {
+        let config = ReadViewConfig {
+            max_retries,
+            ..Default::default()
+        };
+        ReadDatabase::new(Database::default(), Database::<OffChain>::default(), config)
+            .view_at(0.into())
+            .unwrap()
+    }
+
+    fn transient_error() -> StorageError {
+        StorageError::DatabaseError(Box::new("the database is locked"))
+    }
+
+    #[test]
+    fn retry__retries_transient_errors_up_to_the_limit() {
+        // Given
+        let view = retrying_view(2);
+        let mut attempts = 0u32;
+
+        // When
+        let result = view.retry(|| {
+            attempts = attempts.saturating_add(1);
+            if attempts < 3 {
+                Err(transient_error())
+            } else {
+                Ok(attempts)
+            }
+        });
+        let exhausted = view.retry(|| Err::<(), _>(transient_error()));
+
+        // Then
+        assert_eq!(result.unwrap(), 3);
+        assert!(matches!(exhausted, Err(StorageError::DatabaseError(_))));
+    }
+
+    #[test]
+    fn retry__does_not_retry_other_errors() {
+        // Given
+        let view = retrying_view(2);
+        let mut attempts = 0u32;
+
+        // When
+        let result = view.retry(|| {
+            attempts = attempts.saturating_add(1);
+            Err::<(), _>(not_found!("Test"))
+        });
+
+        // Then
+        assert!(matches!(result, Err(StorageError::NotFound(_, _))));
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn retry_iter__does_not_restart_the_iteration_mid_stream() {
+        // Given
+        let view = retrying_view(2);
+        let mut opened = 0u32;
+
+        // When
+        let items = view
+            .retry_iter(|| {
+                opened = opened.saturating_add(1);
+                let first = if opened == 1 {
+                    Err(transient_error())
+                } else {
+                    Ok(1)
+                };
+                [first, Err(transient_error()), Ok(2)]
+                    .into_iter()
+                    .into_boxed()
+            })
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(opened, 2);
+        assert!(matches!(
+            items[..],
+            [Ok(1), Err(StorageError::DatabaseError(_)), Ok(2)]
+        ));
+    }
+
+    #[test]
+    fn view__reuses_the_views_until_the_height_advances() {
+        // Given
+        let on_chain = Database::default();
+        let mut on_chain_writer = on_chain.clone();
+        on_chain_writer
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&0.into(), &block(0))
+            .unwrap();
+        let database = ReadDatabase::new(
+            on_chain,
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        );
+
+        // When
+        let first = database.view().unwrap();
+        let second = database.view().unwrap();
+        on_chain_writer
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&1.into(), &block(1))
+            .unwrap();
+        let third = database.view().unwrap();
+
+        // Then
+        assert!(Arc::ptr_eq(&first.on_chain, &second.on_chain));
+        assert!(!Arc::ptr_eq(&second.on_chain, &third.on_chain));
+        assert_eq!(third.pinned_height(), 1.into());
+    }
+
+    /// The provider of both views from the same snapshot, counting the snapshots.
+    struct SharedDatabase {
+        on_chain: Database,
+        off_chain: Database<OffChain>,
+        snapshots: Arc<AtomicUsize>,
+    }
+
+    impl AtomicView for SharedDatabase {
+        type View = (Database, Database<OffChain>);
+        type Height = BlockHeight;
+
+        fn latest_height(&self) -> Option<BlockHeight> {
+            self.on_chain.latest_height()
+        }
+
+        fn view_at(&self, _: &BlockHeight) -> StorageResult<Self::View> {
+            Ok(self.latest_view())
+        }
+
+        fn latest_view(&self) -> Self::View {
+            self.snapshots.fetch_add(1, Ordering::SeqCst);
+            (self.on_chain.clone(), self.off_chain.clone())
+        }
+    }
+
+    #[test]
+    fn new_shared__takes_one_snapshot_per_view_at_the_same_height() {
+        // Given
+        let mut on_chain = Database::default();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&0.into(), &block(0))
+            .unwrap();
+        let snapshots = Arc::new(AtomicUsize::new(0));
+        let shared = SharedDatabase {
+            on_chain,
+            off_chain: Database::<OffChain>::default(),
+            snapshots: snapshots.clone(),
+        };
+        let database = ReadDatabase::new_shared(shared, ReadViewConfig::default());
+
+        // When
+        let view = database.view().unwrap();
+
+        // Then
+        assert_eq!(snapshots.load(Ordering::SeqCst), 1);
+        assert!(view.is_consistent());
+        assert_eq!(view.database_heights(), (Some(0.into()), Some(0.into())));
+    }
+
+    #[test]
+    fn owned_coins__skips_coins_missing_in_on_chain_database() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let unspent = UtxoId::new(TxId::from([1; 32]), 0);
+        let spent = UtxoId::new(TxId::from([2; 32]), 0);
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let coin = CompressedCoin::default();
+        on_chain
+            .storage_as_mut::<Coins>()
+            .insert(&unspent, &coin)
+            .unwrap();
+        for utxo_id in [unspent, spent] {
+            off_chain
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(&owner, &utxo_id), &())
+                .unwrap();
+        }
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view_at(0.into())
+            .unwrap();
+
+        // When
+        let coins = view
+            .owned_coins(&owner, None, IterDirection::Forward)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        assert_eq!(coins, vec![(unspent, coin)]);
+    }
+
+    #[test]
+    fn old_transaction_at__returns_none_for_index_out_of_range() {
+        // Given
+        let tx = Transaction::default_test_tx();
+        let block = block_with_transactions(1, vec![tx.clone()]);
+        let tx_id = block.transactions()[0];
+        let mut off_chain = Database::<OffChain>::default();
+        off_chain
+            .storage_as_mut::<OldFuelBlocks>()
+            .insert(&1.into(), &block)
+            .unwrap();
+        off_chain
+            .storage_as_mut::<OldTransactions>()
+            .insert(&tx_id, &tx)
+            .unwrap();
+
+        // When
+        let existing = off_chain.old_transaction_at(1.into(), 0).unwrap();
+        let out_of_range = off_chain.old_transaction_at(1.into(), 1).unwrap();
+
+        // Then
+        assert_eq!(existing, Some(tx));
+        assert_eq!(out_of_range, None);
+    }
+
+    #[test]
+    fn blocks__error_carries_the_source_database() {
+        // Given
+        let mut on_chain = Database::default();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&1.into(), &block(1))
+            .unwrap();
+        let mut off_chain = Database::<OffChain>::default();
+        let mut tx = off_chain.write_transaction();
+        tx.put(
+            &0u32.to_be_bytes(),
+            OldFuelBlocks::column(),
+            Arc::new(vec![0xff]),
+        )
+        .unwrap();
+        tx.commit().unwrap();
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view()
+            .unwrap();
+
+        // When
+        let error = view
+            .blocks(None, IterDirection::Forward)
+            .next()
+            .unwrap()
+            .unwrap_err();
+
+        // Then
+        let StorageError::Other(error) = error else {
+            panic!("Expected the error to be tagged, got {error:?}");
+        };
+        assert!(matches!(
+            error.downcast_ref::<ReadViewError>(),
+            Some(ReadViewError::Block(BlockSource::OffChain))
+        ));
+    }
+
+    #[test]
+    fn latest_genesis_height__is_fetched_once_per_view() {
+        // Given
+        let mut on_chain = Database::default();
+        for height in 5..10 {
+            on_chain
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height.into(), &block(height))
+                .unwrap();
+        }
+        let read_database = ReadDatabase::new(
+            on_chain.clone(),
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        );
+        let view = read_database.view().unwrap();
+        assert_eq!(view.latest_genesis_height().unwrap(), 5.into());
+
+        // When
+        let mut tx = on_chain.write_transaction();
+        tx.storage_as_mut::<FuelBlocks>().remove(&5.into()).unwrap();
+        tx.storage_as_mut::<FuelBlocks>()
+            .insert(&10.into(), &block(10))
+            .unwrap();
+        tx.commit().unwrap();
+
+        // Then
+        assert_eq!(view.latest_genesis_height().unwrap(), 5.into());
+        assert_eq!(
+            read_database
+                .view()
+                .unwrap()
+                .latest_genesis_height()
+                .unwrap(),
+            6.into()
+        );
+    }
+
+    #[test]
+    fn message_by_nonce__returns_none_for_unknown_nonce() {
+        // Given
+        let mut message = Message::default();
+        message.set_nonce([1; 32].into());
+        let mut on_chain = Database::default();
+        on_chain
+            .storage_as_mut::<Messages>()
+            .insert(message.nonce(), &message)
+            .unwrap();
+        let view = ReadDatabase::new(
+            on_chain,
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        )
+        .view_at(0.into())
+        .unwrap();
+
+        // When
+        let existing = view.message_by_nonce(&[1; 32].into()).unwrap();
+        let unknown = view.message_by_nonce(&[2; 32].into()).unwrap();
+
+        // Then
+        assert_eq!(existing, Some(message));
+        assert_eq!(unknown, None);
+    }
+
+    #[test]
+    fn owned_messages__skips_messages_missing_on_chain() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let other_owner = Address::from([2; 32]);
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let messages = [(owner, 1u8, true), (owner, 2, false), (owner, 3, true)]
+            .into_iter()
+            .chain([(other_owner, 4, true)]);
+        for (recipient, nonce, unspent) in messages {
+            let nonce = Nonce::from([nonce; 32]);
+            let mut message = Message::default();
+            message.set_nonce(nonce);
+            if unspent {
+                on_chain
+                    .storage_as_mut::<Messages>()
+                    .insert(&nonce, &message)
+                    .unwrap();
+            }
+            off_chain
+                .storage_as_mut::<OwnedMessageIds>()
+                .insert(&OwnedMessageKey::new(&recipient, &nonce), &())
+                .unwrap();
+        }
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view_at(0.into())
+            .unwrap();
+        let nonces = |start, direction| {
+            view.owned_messages(&owner, start, direction)
+                .map(|result| {
+                    let (nonce, message) = result.unwrap();
+                    assert_eq!(message.nonce(), &nonce);
+                    nonce
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // When
+        let forward = nonces(None, IterDirection::Forward);
+        let reverse = nonces(Some(Nonce::from([3; 32])), IterDirection::Reverse);
+
+        // Then
+        assert_eq!(forward, vec![Nonce::from([1; 32]), Nonce::from([3; 32])]);
+        assert_eq!(reverse, vec![Nonce::from([3; 32]), Nonce::from([1; 32])]);
+    }
+
+    #[test]
+    fn dump_owner__writes_coins_and_messages_as_json_lines() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let other_owner = Address::from([2; 32]);
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let mut coins = vec![];
+        for (coin_owner, tx_id) in [(owner, 1u8), (other_owner, 2), (owner, 3)] {
+            let utxo_id = UtxoId::new(TxId::from([tx_id; 32]), 0);
+            let mut coin = CompressedCoin::default();
+            coin.set_owner(coin_owner);
+            coin.set_amount(u64::from(tx_id));
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(&utxo_id, &coin)
+                .unwrap();
+            off_chain
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(&coin_owner, &utxo_id), &())
+                .unwrap();
+            if coin_owner == owner {
+                coins.push(OwnerDumpEntry::Coin(coin.uncompress(utxo_id)));
+            }
+        }
+        let mut message = Message::default();
+        message.set_nonce([4; 32].into());
+        message.set_recipient(owner);
+        on_chain
+            .storage_as_mut::<Messages>()
+            .insert(message.nonce(), &message)
+            .unwrap();
+        off_chain
+            .storage_as_mut::<OwnedMessageIds>()
+            .insert(&OwnedMessageKey::new(&owner, message.nonce()), &())
+            .unwrap();
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view_at(0.into())
+            .unwrap();
+        let mut output = vec![];
+
+        // When
+        view.dump_owner(&owner, &mut output).unwrap();
+
+        // Then
+        let entries = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<OwnerDumpEntry>(line).unwrap())
+            .collect::<Vec<_>>();
+        let mut expected = coins;
+        expected.push(OwnerDumpEntry::Message(message));
+        assert_eq!(entries, expected);
+    }
+
+    #[test]
+    fn messages_at_da_height__yields_only_messages_of_the_da_height() {
+        // Given
+        let mut on_chain = Database::default();
+        for (nonce, da_height) in [(1u8, 5u64), (2, 4), (3, 5), (4, 6)] {
+            let mut message = Message::default();
+            message.set_nonce([nonce; 32].into());
+            message.set_da_height(da_height.into());
+            on_chain
+                .storage_as_mut::<Messages>()
+                .insert(message.nonce(), &message)
+                .unwrap();
+        }
+        let view = ReadDatabase::new(
+            on_chain,
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        )
+        .view_at(0.into())
+        .unwrap();
+
+        // When
+        let nonces = view
+            .messages_at_da_height(5.into())
+            .map(|message| *message.unwrap().nonce())
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(nonces, vec![Nonce::from([1; 32]), Nonce::from([3; 32])]);
+    }
+
+    #[test]
+    fn blocks_tagged__tags_blocks_by_the_side_of_the_regenesis() {
+        // Given
+        let view = read_database(3..5, 5..7).view().unwrap();
+
+        // When
+        let tagged = view
+            .blocks_tagged(None, IterDirection::Forward)
+            .map(|result| {
+                let (origin, block) = result.unwrap();
+                (u32::from(*block.header().height()), origin)
+            })
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(
+            tagged,
+            vec![
+                (3, BlockOrigin::PreRegenesis),
+                (4, BlockOrigin::PreRegenesis),
+                (5, BlockOrigin::PostRegenesis),
+                (6, BlockOrigin::PostRegenesis),
+            ]
+        );
+    }
+
+    #[test]
+    fn blocks__fails_when_old_blocks_overlap_with_on_chain_blocks() {
+        // Given
+        let view = read_database(0..7, 5..10).view().unwrap();
+
+        // When
+        let error = view
+            .blocks(None, IterDirection::Forward)
+            .next()
+            .unwrap()
+            .unwrap_err();
+
+        // Then
+        let StorageError::Other(error) = error else {
+            panic!("Expected the regenesis overlap error, got {error:?}");
+        };
+        assert!(matches!(
+            error.downcast_ref::<ReadViewError>(),
+            Some(ReadViewError::RegenesisOverlap {
+                last_old_block,
+                genesis_height,
+            }) if *last_old_block == 6.into() && *genesis_height == 5.into()
+        ));
+    }
+
+    #[test]
+    fn blocks__drops_the_old_block_duplicating_the_genesis_block() {
+        // Given
+        let view = read_database(0..6, 5..10).view().unwrap();
+
+        // When
+        let forward = heights(view.blocks(None, IterDirection::Forward));
+        let reverse = heights(view.blocks(None, IterDirection::Reverse));
+        let from_old_block = heights(view.blocks(Some(3.into()), IterDirection::Forward));
+        let from_new_block = heights(view.blocks(Some(7.into()), IterDirection::Reverse));
+
+        // Then
+        assert_eq!(forward, (0..10).collect::<Vec<_>>());
+        assert_eq!(reverse, (0..10).rev().collect::<Vec<_>>());
+        assert_eq!(from_old_block, (3..10).collect::<Vec<_>>());
+        assert_eq!(from_new_block, (0..8).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn owned_coins_ids_by_asset__returns_only_coins_of_the_asset() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([1; 32]);
+        let other_asset_id = AssetId::from([2; 32]);
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let coins = [
+            (UtxoId::new(TxId::from([1; 32]), 0), asset_id),
+            (UtxoId::new(TxId::from([2; 32]), 0), other_asset_id),
+            (UtxoId::new(TxId::from([3; 32]), 0), asset_id),
+        ];
+        for (utxo_id, asset_id) in coins {
+            let mut coin = CompressedCoin::default();
+            coin.set_asset_id(asset_id);
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(&utxo_id, &coin)
+                .unwrap();
+            off_chain
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(&owner, &utxo_id), &())
+                .unwrap();
+        }
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view_at(0.into())
+            .unwrap();
+
+        // When
+        let ids = view
+            .owned_coins_ids_by_asset(&owner, &asset_id, None, IterDirection::Reverse)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        assert_eq!(ids, vec![coins[2].0, coins[0].0]);
+    }
+
+    #[test]
+    fn coin_exists__is_false_for_spent_or_unknown_coins() {
+        // Given
+        let unspent = UtxoId::new(TxId::from([1; 32]), 0);
+        let unknown = UtxoId::new(TxId::from([2; 32]), 0);
+        let mut on_chain = Database::default();
+        on_chain
+            .storage_as_mut::<Coins>()
+            .insert(&unspent, &CompressedCoin::default())
+            .unwrap();
+        let view = ReadDatabase::new(
+            on_chain,
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        )
+        .view_at(0.into())
+        .unwrap();
+
+        // When
+        let unspent_exists = view.coin_exists(&unspent).unwrap();
+        let unknown_exists = view.coin_exists(&unknown).unwrap();
+
+        // Then
+        assert!(unspent_exists);
+        assert!(!unknown_exists);
+    }
+
+    #[test]
+    fn coin_with_status__tells_spent_coins_from_unknown_ones() {
+        // Given
+        let coin_output = |amount| Output::Coin {
+            to: Default::default(),
+            amount,
+            asset_id: Default::default(),
+        };
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_output(coin_output(10))
+            .add_output(coin_output(0))
+            .finalize_as_transaction();
+        let tx_id = tx.id(&ChainId::default());
+        let unspent = UtxoId::new(TxId::from([1; 32]), 0);
+        let spent = UtxoId::new(tx_id, 0);
+        let without_amount = UtxoId::new(tx_id, 1);
+        let out_of_range = UtxoId::new(tx_id, 2);
+        let unknown = UtxoId::new(TxId::from([2; 32]), 0);
+        let coin = CompressedCoin::default();
+        let mut on_chain = Database::default();
+        on_chain
+            .storage_as_mut::<Coins>()
+            .insert(&unspent, &coin)
+            .unwrap();
+        on_chain
+            .storage_as_mut::<Transactions>()
+            .insert(&tx_id, &tx)
+            .unwrap();
+        let view = ReadDatabase::new(
+            on_chain,
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        )
+        .view_at(0.into())
+        .unwrap();
+
+        // When
+        let statuses = [unspent, spent, without_amount, out_of_range, unknown]
+            .map(|utxo_id| view.coin_with_status(&utxo_id).unwrap());
+
+        // Then
+        assert_eq!(
+            statuses,
+            [
+                CoinStatus::Unspent(coin),
+                CoinStatus::Spent,
+                CoinStatus::Unknown,
+                CoinStatus::Unknown,
+                CoinStatus::Unknown,
+            ]
+        );
+    }
+
+    #[test]
+    fn contract_deployed_height__uses_the_tx_pointer_of_the_latest_utxo() {
+        // Given
+        let contract = ContractId::from([1; 32]);
+        let unknown = ContractId::from([2; 32]);
+        let mut on_chain = Database::default();
+        let utxo_id = UtxoId::new(TxId::from([1; 32]), 0);
+        let utxo_info =
+            ContractUtxoInfo::V1((utxo_id, TxPointer::new(7.into(), 2)).into());
+        on_chain
+            .storage_as_mut::<ContractsLatestUtxo>()
+            .insert(&contract, &utxo_info)
+            .unwrap();
+        let view = ReadDatabase::new(
+            on_chain,
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        )
+        .view_at(0.into())
+        .unwrap();
+
+        // When
+        let deployed = view.contract_deployed_height(&contract).unwrap();
+        let unknown_deployed = view.contract_deployed_height(&unknown).unwrap();
+
+        // Then
+        assert_eq!(deployed, Some(7.into()));
+        assert_eq!(unknown_deployed, None);
+    }
+
+    #[test]
+    fn coins_by_asset__yields_coins_of_the_asset_across_owners() {
+        // Given
+        let asset_id = AssetId::from([1; 32]);
+        let other_asset_id = AssetId::from([2; 32]);
+        let mut on_chain = Database::default();
+        let utxo_id = |i: u8| UtxoId::new(TxId::from([i; 32]), 0);
+        let owner = |i: u8| Address::from([i; 32]);
+        for (i, asset_id) in [(1u8, asset_id), (2, other_asset_id), (3, asset_id)] {
+            let mut coin = CompressedCoin::default();
+            coin.set_owner(owner(i));
+            coin.set_asset_id(asset_id);
+            coin.set_amount(u64::from(i));
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(&utxo_id(i), &coin)
+                .unwrap();
+        }
+        let view = ReadDatabase::new(
+            on_chain,
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        )
+        .view_at(0.into())
+        .unwrap();
+
+        // When
+        let found = view
+            .coins_by_asset(&asset_id, None, IterDirection::Forward)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        assert_eq!(
+            found,
+            vec![(owner(1), utxo_id(1), 1), (owner(3), utxo_id(3), 3)]
+        );
+    }
+
+    #[test]
+    fn owned_balance__sums_the_coins_of_the_asset_without_overflow() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([1; 32]);
+        let other_asset_id = AssetId::from([2; 32]);
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let coins = [
+            (UtxoId::new(TxId::from([1; 32]), 0), asset_id, u64::MAX),
+            (UtxoId::new(TxId::from([2; 32]), 0), other_asset_id, 5),
+            (UtxoId::new(TxId::from([3; 32]), 0), asset_id, 2),
+        ];
+        for (utxo_id, asset_id, amount) in coins {
+            let mut coin = CompressedCoin::default();
+            coin.set_asset_id(asset_id);
+            coin.set_amount(amount);
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(&utxo_id, &coin)
+                .unwrap();
+            off_chain
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(&owner, &utxo_id), &())
+                .unwrap();
+        }
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view_at(0.into())
+            .unwrap();
+
+        // When
+        let balance = view.owned_balance(&owner, &asset_id).unwrap();
+
+        // Then
+        assert_eq!(balance, u128::from(u64::MAX).saturating_add(2));
+    }
+
+    #[test]
+    fn contract_total_value__reads_only_the_base_asset() {
+        // Given
+        let contract = ContractId::from([1; 32]);
+        let base_asset = AssetId::from([3; 32]);
+        let mut on_chain = contract_balances_database(&contract);
+        on_chain
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&(&contract, &base_asset).into(), &u64::MAX)
+            .unwrap();
+        let view = ReadDatabase::new(
+            on_chain,
+            Database::<OffChain>::default(),
+            ReadViewConfig::default(),
+        )
+        .view_at(0.into())
+        .unwrap();
+
+        // When
+        let total = view.contract_total_value(contract, &base_asset).unwrap();
+        let missing = view
+            .contract_total_value(contract, &AssetId::from([4; 32]))
+            .unwrap();
+
+        // Then
+        assert_eq!(total, u128::from(u64::MAX));
+        assert_eq!(missing, 0);
+    }
+
+    #[test]
+    fn owned_asset_ids__yields_each_asset_once_in_utxo_id_order() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let other_owner = Address::from([2; 32]);
+        let mut on_chain = Database::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let coins = [
+            (owner, UtxoId::new(TxId::from([1; 32]), 0), 2u8),
+            (owner, UtxoId::new(TxId::from([2; 32]), 0), 1),
+            (owner, UtxoId::new(TxId::from([3; 32]), 0), 2),
+            (owner, UtxoId::new(TxId::from([4; 32]), 0), 1),
+            (other_owner, UtxoId::new(TxId::from([5; 32]), 0), 3),
+        ];
+        for (owner, utxo_id, asset) in coins {
+            let mut coin = CompressedCoin::default();
+            coin.set_asset_id(AssetId::from([asset; 32]));
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(&utxo_id, &coin)
+                .unwrap();
+            off_chain
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(&owner, &utxo_id), &())
+                .unwrap();
+        }
+        let view = ReadDatabase::new(on_chain, off_chain, ReadViewConfig::default())
+            .view_at(0.into())
+            .unwrap();
+
+        // When
+        let asset_ids = view
+            .owned_asset_ids(&owner)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        assert_eq!(
+            asset_ids,
+            vec![AssetId::from([2; 32]), AssetId::from([1; 32])]
+        );
+    }
+
+    #[test]
+    fn relayed_tx_status_history__yields_the_current_status() {
+        // Given
+        let id = Bytes32::from([1; 32]);
+        let status = RelayedTransactionStatus::Failed {
+            block_height: 1.into(),
+            failure: "failure".to_string(),
+        };
+        let mut off_chain = Database::<OffChain>::default();
+        off_chain
+            .storage_as_mut::<RelayedTransactionStatuses>()
+            .insert(&id, &status)
+            .unwrap();
+
+        // When
+        let history = off_chain
+            .relayed_tx_status_history(id)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+        let unknown = off_chain
+            .relayed_tx_status_history(Bytes32::from([2; 32]))
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        assert_eq!(history, vec![status]);
+        assert_eq!(unknown, vec![]);
+    }
+
+    #[test]
+    fn relayed_tx_statuses__paginates_from_the_cursor_in_both_directions() {
+        // Given
+        let mut off_chain = Database::<OffChain>::default();
+        let statuses: Vec<_> = (1..=3u8)
+            .map(|i| {
+                let status = RelayedTransactionStatus::Failed {
+                    block_height: u32::from(i).into(),
+                    failure: format!("failure {i}"),
+                };
+                (Bytes32::from([i; 32]), status)
+            })
+            .collect();
+        for (id, status) in &statuses {
+            off_chain
+                .storage_as_mut::<RelayedTransactionStatuses>()
+                .insert(id, status)
+                .unwrap();
+        }
+        let view =
+            ReadDatabase::new(Database::default(), off_chain, ReadViewConfig::default())
+                .view_at(0.into())
+                .unwrap();
+        let cursor = Some(Bytes32::from([2; 32]));
+
+        // When
+        let all = DatabaseRelayedTransactions::relayed_tx_statuses(
+            &view,
+            None,
+            IterDirection::Forward,
+        )
+        .collect::<StorageResult<Vec<_>>>()
+        .unwrap();
+        let forward = DatabaseRelayedTransactions::relayed_tx_statuses(
+            &view,
+            cursor,
+            IterDirection::Forward,
+        )
+        .collect::<StorageResult<Vec<_>>>()
+        .unwrap();
+        let reverse = DatabaseRelayedTransactions::relayed_tx_statuses(
+            &view,
+            cursor,
+            IterDirection::Reverse,
+        )
+        .collect::<StorageResult<Vec<_>>>()
+        .unwrap();
+
+        // Then
+        assert_eq!(all, statuses);
+        assert_eq!(forward, statuses[1..].to_vec());
+        assert_eq!(
+            reverse,
+            statuses[..2].iter().rev().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn block_count__spans_regenesis_boundary() {
+        // Given
+        let view = read_database(2..5, 5..10).view().unwrap();
+
+        // When
+        let all = view.block_count(None, None).unwrap();
+        let straddling = view.block_count(Some(3.into()), Some(7.into())).unwrap();
+        let tail = view.block_count(Some(8.into()), None).unwrap();
+        let before_first = view.block_count(None, Some(2.into())).unwrap();
+        let inverted = view.block_count(Some(7.into()), Some(3.into())).unwrap();
+
+        // Then
+        assert_eq!(all, 8);
+        assert_eq!(straddling, 4);
+        assert_eq!(tail, 2);
+        assert_eq!(before_first, 0);
+        assert_eq!(inverted, 0);
+    }
+
+    #[test]
+    fn contract_salts__skips_unknown_contracts_and_orders_by_id() {
+        // Given
+        let mut off_chain = Database::<OffChain>::default();
+        for byte in [1u8, 3, 5, 7] {
+            off_chain
+                .storage_as_mut::<ContractsInfo>()
+                .insert(
+                    &ContractId::from([byte; 32]),
+                    &ContractsInfoType::V1(Salt::new([byte; 32]).into()),
+                )
+                .unwrap();
+        }
+        let view =
+            ReadDatabase::new(Database::default(), off_chain, ReadViewConfig::default())
+                .view()
+                .unwrap();
+        let requested = [5u8, 2, 1, 5, 9].map(|byte| ContractId::from([byte; 32]));
+
+        // When
+        let salts = view
+            .contract_salts(&requested)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+        let empty = view
+            .contract_salts(&[])
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        let expected: Vec<_> = [1u8, 5]
+            .map(|byte| (ContractId::from([byte; 32]), Salt::new([byte; 32])))
+            .to_vec();
+        assert_eq!(salts, expected);
+        assert_eq!(empty, vec![]);
     }
 }
@@ -2,12 +2,14 @@ use crate::fuel_core_graphql_api::{
     database::{
         OffChainView,
         OnChainView,
+        SharedView,
     },
     ports::{
         OffChainDatabase,
         OnChainDatabase,
     },
 };
+use fuel_core_services::SharedMutex;
 use fuel_core_storage::{
     transactional::AtomicView,
     Result as StorageResult,
@@ -16,16 +18,70 @@ use std::sync::Arc;
 
 /// The GraphQL can't work with the generics in [`async_graphql::Context::data_unchecked`] and requires a known type.
 /// It is an `Arc` wrapper around the generic for on-chain and off-chain databases.
-pub struct ArcWrapper<Provider, ArcView> {
+///
+/// The view for the latest height is cached and shared between all callers
+/// until the height of the `inner` provider advances after the commit.
+pub struct ArcWrapper<Provider, ArcView>
+where
+    Provider: AtomicView,
+{
     inner: Provider,
-    _marker: core::marker::PhantomData<ArcView>,
+    latest: SharedMutex<Option<(Provider::Height, ArcView)>>,
 }
 
-impl<Provider, ArcView> ArcWrapper<Provider, ArcView> {
+impl<Provider, ArcView> ArcWrapper<Provider, ArcView>
+where
+    Provider: AtomicView,
+{
     pub fn new(inner: Provider) -> Self {
         Self {
             inner,
-            _marker: core::marker::PhantomData,
+            latest: SharedMutex::new(None),
+        }
+    }
+}
+
+impl<Provider, ArcView> ArcWrapper<Provider, ArcView>
+where
+    Provider: AtomicView,
+    Provider::Height: PartialEq + Clone,
+    ArcView: Clone,
+{
+    /// Returns the view for the latest height, reusing the cached one
+    /// if the height hasn't changed since it was created.
+    ///
+    /// The cache is replaced while the lock is held, so a caller never
+    /// observes a view created for an outdated height.
+    pub fn latest_view_cached(
+        &self,
+        wrap: impl FnOnce(Provider::View) -> ArcView,
+    ) -> ArcView {
+        let Some(height) = self.inner.latest_height() else {
+            return wrap(self.inner.latest_view())
+        };
+        let mut latest = self.latest.lock();
+        match latest.as_ref() {
+            Some((cached_height, view)) if *cached_height == height => view.clone(),
+            _ => {
+                let view = wrap(self.inner.latest_view());
+                *latest = Some((height, view.clone()));
+                view
+            }
+        }
+    }
+
+    /// Returns the view at the `height`. The cached view is reused if the `height`
+    /// is the latest one, views at historical heights are created on every call.
+    fn view_at_cached(
+        &self,
+        height: &Provider::Height,
+        wrap: impl FnOnce(Provider::View) -> ArcView,
+    ) -> StorageResult<ArcView> {
+        if self.inner.latest_height().as_ref() == Some(height) {
+            Ok(self.latest_view_cached(wrap))
+        } else {
+            let view = self.inner.view_at(height)?;
+            Ok(wrap(view))
         }
     }
 }
@@ -34,6 +90,7 @@ impl<Provider, View, Height> AtomicView for ArcWrapper<Provider, OnChainView>
 where
     Provider: AtomicView<View = View, Height = Height>,
     View: OnChainDatabase + 'static,
+    Height: PartialEq + Clone + Send,
 {
     type View = OnChainView;
     type Height = Height;
@@ -43,12 +100,11 @@ where
     }
 
     fn view_at(&self, height: &Height) -> StorageResult<Self::View> {
-        let view = self.inner.view_at(height)?;
-        Ok(Arc::new(view))
+        self.view_at_cached(height, |view| Arc::new(view))
     }
 
     fn latest_view(&self) -> Self::View {
-        Arc::new(self.inner.latest_view())
+        self.latest_view_cached(|view| Arc::new(view))
     }
 }
 
@@ -56,6 +112,7 @@ impl<Provider, View, Height> AtomicView for ArcWrapper<Provider, OffChainView>
 where
     Provider: AtomicView<View = View, Height = Height>,
     View: OffChainDatabase + 'static,
+    Height: PartialEq + Clone + Send,
 {
     type View = OffChainView;
     type Height = Height;
@@ -65,11 +122,43 @@ where
     }
 
     fn view_at(&self, height: &Height) -> StorageResult<Self::View> {
-        let view = self.inner.view_at(height)?;
-        Ok(Arc::new(view))
+        self.view_at_cached(height, |view| Arc::new(view))
+    }
+
+    fn latest_view(&self) -> Self::View {
+        self.latest_view_cached(|view| Arc::new(view))
+    }
+}
+
+impl<Provider, OnChain, OffChain, Height> AtomicView for ArcWrapper<Provider, SharedView>
+where
+    Provider: AtomicView<View = (OnChain, OffChain), Height = Height>,
+    OnChain: OnChainDatabase + 'static,
+    OffChain: OffChainDatabase + 'static,
+    Height: PartialEq + Clone + Send,
+{
+    type View = SharedView;
+    type Height = Height;
+
+    fn latest_height(&self) -> Option<Self::Height> {
+        self.inner.latest_height()
+    }
+
+    fn view_at(&self, height: &Height) -> StorageResult<Self::View> {
+        self.view_at_cached(height, shared_view)
     }
 
     fn latest_view(&self) -> Self::View {
-        Arc::new(self.inner.latest_view())
+        self.latest_view_cached(shared_view)
     }
 }
+
+fn shared_view<OnChain, OffChain>(
+    (on_chain, off_chain): (OnChain, OffChain),
+) -> SharedView
+where
+    OnChain: OnChainDatabase + 'static,
+    OffChain: OffChainDatabase + 'static,
+{
+    (Arc::new(on_chain), Arc::new(off_chain))
+}
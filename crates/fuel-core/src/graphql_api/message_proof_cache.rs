@@ -0,0 +1,241 @@
+use fuel_core_types::{
+    entities::relayer::message::MerkleProof,
+    fuel_types::BlockHeight,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        Mutex,
+        MutexGuard,
+        PoisonError,
+    },
+};
+
+/// The cache of the message proofs shared by all views of the `ReadDatabase`.
+///
+/// The block history tree at the commit block never changes, so the proofs built
+/// from it can be reused by the later requests against the same commit block.
+/// The proofs are grouped by the commit block height, and the least recently used
+/// commit block heights are evicted first. Each commit block height keeps at most
+/// `proofs_per_commit` proofs, evicting its least recently used proof first, so
+/// the cache holds at most `capacity * proofs_per_commit` proofs.
+pub(crate) struct MessageProofCache {
+    /// The maximum number of the cached commit block heights.
+    /// Nothing is cached if it is zero.
+    capacity: usize,
+    /// The maximum number of the cached proofs per commit block height.
+    /// Nothing is cached if it is zero.
+    proofs_per_commit: usize,
+    /// The number of blocks below the latest height for which the proofs are kept.
+    horizon: u32,
+    commits: Mutex<Commits>,
+}
+
+#[derive(Default)]
+struct Commits {
+    /// Incremented on every access to order the commit block heights and the proofs
+    /// by their last use.
+    clock: u64,
+    proofs: HashMap<BlockHeight, CommitProofs>,
+}
+
+struct CommitProofs {
+    last_used: u64,
+    /// The proofs of the message blocks against the commit block.
+    proofs: HashMap<BlockHeight, CachedProof>,
+}
+
+struct CachedProof {
+    last_used: u64,
+    proof: MerkleProof,
+}
+
+impl Commits {
+    fn tick(&mut self) -> u64 {
+        self.clock = self.clock.wrapping_add(1);
+        self.clock
+    }
+}
+
+impl MessageProofCache {
+    pub fn new(capacity: usize, proofs_per_commit: usize, horizon: u32) -> Self {
+        Self {
+            capacity,
+            proofs_per_commit,
+            horizon,
+            commits: Mutex::new(Commits::default()),
+        }
+    }
+
+    /// Returns the cached proof of the message block against the commit block.
+    pub fn get(
+        &self,
+        message_block_height: &BlockHeight,
+        commit_block_height: &BlockHeight,
+    ) -> Option<MerkleProof> {
+        if self.is_disabled() {
+            return None
+        }
+        let mut commits = self.lock();
+        let clock = commits.tick();
+        let commit = commits.proofs.get_mut(commit_block_height)?;
+        commit.last_used = clock;
+        let cached = commit.proofs.get_mut(message_block_height)?;
+        cached.last_used = clock;
+        Some(cached.proof.clone())
+    }
+
+    /// Caches the proof of the message block against the commit block.
+    ///
+    /// The commit block heights more than the horizon below the `latest_height`
+    /// are evicted, and so is the least recently used one if the cache is full.
+    /// The least recently used proof of the commit block is evicted if it already
+    /// holds `proofs_per_commit` proofs.
+    pub fn insert(
+        &self,
+        message_block_height: BlockHeight,
+        commit_block_height: BlockHeight,
+        proof: MerkleProof,
+        latest_height: BlockHeight,
+    ) {
+        let oldest = u32::from(latest_height).saturating_sub(self.horizon);
+        if self.is_disabled() || u32::from(commit_block_height) < oldest {
+            return
+        }
+        let mut commits = self.lock();
+        let clock = commits.tick();
+        commits
+            .proofs
+            .retain(|height, _| u32::from(*height) >= oldest);
+
+        if !commits.proofs.contains_key(&commit_block_height)
+            && commits.proofs.len() >= self.capacity
+        {
+            let least_recently_used = commits
+                .proofs
+                .iter()
+                .min_by_key(|(_, commit)| commit.last_used)
+                .map(|(height, _)| *height);
+            if let Some(height) = least_recently_used {
+                commits.proofs.remove(&height);
+            }
+        }
+
+        let commit = commits
+            .proofs
+            .entry(commit_block_height)
+            .or_insert_with(|| CommitProofs {
+                last_used: clock,
+                proofs: HashMap::new(),
+            });
+        commit.last_used = clock;
+
+        if !commit.proofs.contains_key(&message_block_height)
+            && commit.proofs.len() >= self.proofs_per_commit
+        {
+            let least_recently_used = commit
+                .proofs
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(height, _)| *height);
+            if let Some(height) = least_recently_used {
+                commit.proofs.remove(&height);
+            }
+        }
+        commit.proofs.insert(
+            message_block_height,
+            CachedProof {
+                last_used: clock,
+                proof,
+            },
+        );
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.capacity == 0 || self.proofs_per_commit == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Commits> {
+        // The cached proofs stay valid even if a thread panicked while holding the lock.
+        self.commits.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proof(proof_index: u64) -> MerkleProof {
+        MerkleProof {
+            proof_set: vec![],
+            proof_index,
+        }
+    }
+
+    #[test]
+    fn insert__evicts_the_least_recently_used_commit() {
+        // Given
+        let cache = MessageProofCache::new(2, 10, 100);
+        let latest = BlockHeight::from(10);
+        cache.insert(1.into(), 5.into(), proof(1), latest);
+        cache.insert(1.into(), 6.into(), proof(2), latest);
+        let _ = cache.get(&1.into(), &5.into());
+
+        // When
+        cache.insert(1.into(), 7.into(), proof(3), latest);
+
+        // Then
+        assert_eq!(cache.get(&1.into(), &5.into()), Some(proof(1)));
+        assert_eq!(cache.get(&1.into(), &6.into()), None);
+        assert_eq!(cache.get(&1.into(), &7.into()), Some(proof(3)));
+    }
+
+    #[test]
+    fn insert__evicts_the_commits_below_the_horizon() {
+        // Given
+        let cache = MessageProofCache::new(10, 10, 5);
+        cache.insert(1.into(), 5.into(), proof(1), 8.into());
+        cache.insert(1.into(), 9.into(), proof(2), 9.into());
+
+        // When
+        cache.insert(1.into(), 12.into(), proof(3), 12.into());
+        cache.insert(1.into(), 2.into(), proof(4), 12.into());
+
+        // Then
+        assert_eq!(cache.get(&1.into(), &5.into()), None);
+        assert_eq!(cache.get(&1.into(), &9.into()), Some(proof(2)));
+        assert_eq!(cache.get(&1.into(), &12.into()), Some(proof(3)));
+        assert_eq!(cache.get(&1.into(), &2.into()), None);
+    }
+
+    #[test]
+    fn insert__evicts_the_least_recently_used_proof_of_a_full_commit() {
+        // Given
+        let cache = MessageProofCache::new(10, 2, 100);
+        let latest = BlockHeight::from(10);
+        cache.insert(1.into(), 5.into(), proof(1), latest);
+        cache.insert(2.into(), 5.into(), proof(2), latest);
+        let _ = cache.get(&1.into(), &5.into());
+
+        // When
+        cache.insert(3.into(), 5.into(), proof(3), latest);
+
+        // Then
+        assert_eq!(cache.get(&1.into(), &5.into()), Some(proof(1)));
+        assert_eq!(cache.get(&2.into(), &5.into()), None);
+        assert_eq!(cache.get(&3.into(), &5.into()), Some(proof(3)));
+    }
+
+    #[test]
+    fn get__returns_nothing_when_the_cache_is_disabled() {
+        // Given
+        let cache = MessageProofCache::new(0, 10, 100);
+        cache.insert(1.into(), 5.into(), proof(1), 5.into());
+
+        // When
+        let cached = cache.get(&1.into(), &5.into());
+
+        // Then
+        assert_eq!(cached, None);
+    }
+}
@@ -1,8 +1,13 @@
+use crate::fuel_core_graphql_api::storage::transactions::{
+    OwnedTxCursor,
+    TransactionStatuses,
+};
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     iter::{
         BoxedIter,
+        IntoBoxedIter,
         IterDirection,
     },
     tables::{
@@ -23,17 +28,22 @@ use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
         consensus::Consensus,
+        header::BlockHeader,
         primitives::{
             BlockId,
             DaBlockHeight,
         },
     },
-    entities::relayer::{
-        message::{
-            MerkleProof,
-            Message,
+    entities::{
+        coins::coin::CompressedCoin,
+        relayer::{
+            message::{
+                MerkleProof,
+                Message,
+                MessageStatus,
+            },
+            transaction::RelayedTransactionStatus,
         },
-        transaction::RelayedTransactionStatus,
     },
     fuel_tx::{
         Bytes32,
@@ -62,9 +72,16 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
+use itertools::Itertools;
 use std::sync::Arc;
 
-pub trait OffChainDatabase: Send + Sync {
+/// The off-chain database port expected by GraphQL API service.
+///
+/// The tables of the supertraits can be read through the generic
+/// [`StorageInspect`] interface instead of a bespoke method per table.
+pub trait OffChainDatabase:
+    Send + Sync + StorageInspect<TransactionStatuses, Error = StorageError>
+{
     fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight>;
 
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
@@ -76,6 +93,66 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<UtxoId>>;
 
+    /// Returns at most `limit` ids of the coins owned by the `owner`, in the order of
+    /// the [`Self::owned_coins_ids`], along with the continuation cursor.
+    ///
+    /// The `after` cursor is exclusive: the page starts with the first coin after it.
+    /// The continuation cursor is the last `UtxoId` of the page if more coins follow,
+    /// and `None` for the last page. At most `limit + 1` coins are read, so the work
+    /// per page is bounded regardless of the number of owned coins. The `limit`
+    /// is at least one.
+    fn owned_coins_ids_page(
+        &self,
+        owner: &Address,
+        after: Option<UtxoId>,
+        direction: IterDirection,
+        limit: usize,
+    ) -> StorageResult<(Vec<UtxoId>, Option<UtxoId>)> {
+        let limit = limit.max(1);
+        let mut utxo_ids: Vec<_> = self
+            .owned_coins_ids(owner, after, direction)
+            .skip_while(|result| matches!(result, Ok(utxo_id) if Some(*utxo_id) == after))
+            .take(limit.saturating_add(1))
+            .try_collect()?;
+        let next = if utxo_ids.len() > limit {
+            utxo_ids.truncate(limit);
+            utxo_ids.last().copied()
+        } else {
+            None
+        };
+        Ok((utxo_ids, next))
+    }
+
+    /// Returns the ids of the coins owned by any of the `owners`, tagged with the owner.
+    ///
+    /// The per-owner iterators are merged lazily, so the result is globally ordered
+    /// by the `UtxoId`: ascending for the `IterDirection::Forward` and descending
+    /// for the `IterDirection::Reverse`. The `start_coin` is applied to each owner.
+    /// Errors are yielded as soon as any of the underlying iterators produces them.
+    fn owned_coins_ids_multi(
+        &self,
+        owners: &[Address],
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Address, UtxoId)>> {
+        owners
+            .iter()
+            .copied()
+            .map(|owner| {
+                self.owned_coins_ids(&owner, start_coin, direction)
+                    .map(move |result| result.map(|utxo_id| (owner, utxo_id)))
+            })
+            .kmerge_by(move |a, b| match (a, b) {
+                (Ok((_, a)), Ok((_, b))) => match direction {
+                    IterDirection::Forward => a < b,
+                    IterDirection::Reverse => a > b,
+                },
+                (Err(_), _) => true,
+                (_, Err(_)) => false,
+            })
+            .into_boxed()
+    }
+
     fn owned_message_ids(
         &self,
         owner: &Address,
@@ -83,6 +160,14 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<Nonce>>;
 
+    /// Returns the number of messages owned by the `owner`.
+    fn owned_message_count(&self, owner: &Address) -> StorageResult<u64>;
+
+    /// Returns the transactions of the `owner` ordered by the [`TxPointer`].
+    ///
+    /// The `start` cursor is inclusive for the `IterDirection::Forward`: the iteration
+    /// yields items from the cursor onward. For the `IterDirection::Reverse` it is
+    /// exclusive: the iteration yields only items strictly before the cursor.
     fn owned_transactions_ids(
         &self,
         owner: Address,
@@ -90,22 +175,119 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
 
+    /// Returns the transactions of the `owner` after the `cursor` in the `direction`.
+    ///
+    /// The cursor is exclusive in both directions. The transactions sharing
+    /// the [`TxPointer`] of the cursor are ordered by their id, so only the ones
+    /// after the id of the cursor in the `direction` are yielded.
+    fn owned_transactions_ids_from(
+        &self,
+        owner: Address,
+        cursor: Option<OwnedTxCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        let Some(cursor) = cursor else {
+            return self.owned_transactions_ids(owner, None, direction)
+        };
+        let start = match direction {
+            IterDirection::Forward => Some(cursor.tx_pointer),
+            // The reverse start is exclusive, so it starts right after the cursor
+            // to include the other transactions at its `TxPointer`.
+            IterDirection::Reverse => match cursor.tx_pointer.tx_index().checked_add(1) {
+                Some(tx_index) => {
+                    Some(TxPointer::new(cursor.tx_pointer.block_height(), tx_index))
+                }
+                None => cursor
+                    .tx_pointer
+                    .block_height()
+                    .succ()
+                    .map(|height| TxPointer::new(height, 0)),
+            },
+        };
+        self.owned_transactions_ids(owner, start, direction)
+            .skip_while(move |result| match result {
+                Ok((tx_pointer, tx_id)) if *tx_pointer == cursor.tx_pointer => {
+                    match direction {
+                        IterDirection::Forward => *tx_id <= cursor.tx_id,
+                        IterDirection::Reverse => *tx_id >= cursor.tx_id,
+                    }
+                }
+                _ => false,
+            })
+            .into_boxed()
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt>;
 
+    /// Returns the salts of the contracts ordered by the contract id.
+    /// Duplicated ids are resolved once, and contracts without a recorded
+    /// salt are skipped. By default, every contract is looked up separately.
+    fn contract_salts(
+        &self,
+        contract_ids: &[ContractId],
+    ) -> BoxedIter<'_, StorageResult<(ContractId, Salt)>> {
+        let mut contract_ids = contract_ids.to_vec();
+        contract_ids.sort();
+        contract_ids.dedup();
+        contract_ids
+            .into_iter()
+            .filter_map(move |contract_id| match self.contract_salt(&contract_id) {
+                Ok(salt) => Some(Ok((contract_id, salt))),
+                Err(StorageError::NotFound(_, _)) => None,
+                Err(err) => Some(Err(err)),
+            })
+            .into_boxed()
+    }
+
     fn old_blocks(
         &self,
         height: Option<BlockHeight>,
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<CompressedBlock>>;
 
+    /// Checks whether the block from before the regenesis at the `height` exists
+    /// without loading it.
+    fn old_block_exists(&self, height: BlockHeight) -> StorageResult<bool>;
+
     fn old_block_consensus(&self, height: BlockHeight) -> StorageResult<Consensus>;
 
     fn old_transaction(&self, id: &TxId) -> StorageResult<Option<Transaction>>;
 
+    /// Returns the transaction at the `tx_index` position of the old block
+    /// at the `height`, or `None` if the block has fewer transactions.
+    fn old_transaction_at(
+        &self,
+        height: BlockHeight,
+        tx_index: u16,
+    ) -> StorageResult<Option<Transaction>>;
+
     fn relayed_tx_status(
         &self,
         id: Bytes32,
     ) -> StorageResult<Option<RelayedTransactionStatus>>;
+
+    /// Returns the statuses of the relayed transaction ordered from the oldest
+    /// to the newest.
+    ///
+    /// Only the latest status is stored for now, so the iterator yields at most
+    /// one item. Statuses recorded in the future are appended after it.
+    fn relayed_tx_status_history(
+        &self,
+        id: Bytes32,
+    ) -> BoxedIter<'_, StorageResult<RelayedTransactionStatus>> {
+        self.relayed_tx_status(id)
+            .transpose()
+            .into_iter()
+            .into_boxed()
+    }
+
+    /// Returns the statuses of all relayed transactions ordered by the
+    /// transaction id, starting from the `start` id if it is specified.
+    fn relayed_tx_statuses(
+        &self,
+        start: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Bytes32, RelayedTransactionStatus)>>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -115,7 +297,7 @@ pub trait OnChainDatabase:
     + DatabaseBlocks
     + StorageInspect<Transactions, Error = StorageError>
     + DatabaseMessages
-    + StorageInspect<Coins, Error = StorageError>
+    + DatabaseCoins
     + DatabaseContracts
     + DatabaseChain
     + DatabaseMessageProof
@@ -133,23 +315,163 @@ pub trait DatabaseBlocks:
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<CompressedBlock>>;
 
+    /// Returns the headers of the blocks in the same order as [`Self::blocks`],
+    /// spanning the regenesis the same way.
+    ///
+    /// The blocks are stored together with their transaction ids, so the default
+    /// implementation reads the whole block and drops the ids. Implementations with
+    /// the storage layout that allows reading only the headers can override it.
+    fn block_headers(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<BlockHeader>> {
+        self.blocks(height, direction)
+            .map(|result| result.map(|block| block.into_inner().0))
+            .into_boxed()
+    }
+
+    /// Returns blocks from the `start` height up to the `end` height(exclusive)
+    /// in the order defined by the `direction`. For the `IterDirection::Reverse`,
+    /// the `start` height should be above the `end` height.
+    ///
+    /// The range is empty if `start == end`.
+    fn blocks_in_range(
+        &self,
+        start: BlockHeight,
+        end: BlockHeight,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<CompressedBlock>> {
+        let is_empty = match direction {
+            IterDirection::Forward => start >= end,
+            IterDirection::Reverse => start <= end,
+        };
+        if is_empty {
+            return core::iter::empty().into_boxed()
+        }
+
+        self.blocks(Some(start), direction)
+            .take_while(move |result| match result {
+                Ok(block) => {
+                    let height = *block.header().height();
+                    match direction {
+                        IterDirection::Forward => height < end,
+                        IterDirection::Reverse => height > end,
+                    }
+                }
+                Err(_) => true,
+            })
+            .into_boxed()
+    }
+
     fn latest_height(&self) -> StorageResult<BlockHeight>;
 
     /// First (i.e. lowest) height stored in this db.
     fn latest_genesis_height(&self) -> StorageResult<BlockHeight>;
+
+    /// The lowest height of the blocks returned by [`Self::blocks`].
+    /// It is the genesis height unless blocks from before the regenesis are available.
+    fn first_block_height(&self) -> StorageResult<BlockHeight> {
+        self.latest_genesis_height()
+    }
+
+    /// Returns the number of blocks with heights in the `start..end` range.
+    /// A missing bound leaves the range unbounded on that side.
+    ///
+    /// Blocks are stored without gaps, so the count is computed from the first
+    /// and the latest heights without iterating over the blocks.
+    fn block_count(
+        &self,
+        start: Option<BlockHeight>,
+        end: Option<BlockHeight>,
+    ) -> StorageResult<u64> {
+        let first = u64::from(*self.first_block_height()?);
+        let after_latest = u64::from(*self.latest_height()?).saturating_add(1);
+
+        let start = start.map_or(first, |height| u64::from(*height)).max(first);
+        let end = end
+            .map_or(after_latest, |height| u64::from(*height))
+            .min(after_latest);
+        Ok(end.saturating_sub(start))
+    }
+}
+
+/// Trait that specifies all the getters required for coins.
+pub trait DatabaseCoins: StorageInspect<Coins, Error = StorageError> {
+    /// Returns the unspent coins of all owners ordered by the `UtxoId`,
+    /// starting from the `start` coin.
+    fn all_coins(
+        &self,
+        start: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(UtxoId, CompressedCoin)>>;
 }
 
 /// Trait that specifies all the getters required for messages.
 pub trait DatabaseMessages: StorageInspect<Messages, Error = StorageError> {
+    /// Returns the messages ordered by the nonce.
+    ///
+    /// The `start_message_id` cursor is inclusive for the `IterDirection::Forward`.
+    /// For the `IterDirection::Reverse` it is exclusive: the iteration yields only
+    /// messages with nonces strictly less than the cursor.
+    ///
+    /// Unlike the blocks, the messages from before the regenesis aren't kept
+    /// off-chain: the unspent ones are imported into the on-chain database from
+    /// the snapshot, so there is no history to merge with.
     fn all_messages(
         &self,
         start_message_id: Option<Nonce>,
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<Message>>;
 
+    /// Returns the messages that are not spent yet. The order matches
+    /// the [`Self::all_messages`] with spent messages skipped.
+    fn unspent_messages(
+        &self,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Message>>;
+
+    /// Returns the message with the `nonce`, or `None` if there is no such message.
+    ///
+    /// Spent messages are removed from the storage, so `None` is also returned
+    /// for them. Use [`Self::message_is_spent`] to tell them apart.
+    fn message_by_nonce(&self, nonce: &Nonce) -> StorageResult<Option<Message>>;
+
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool>;
 
     fn message_exists(&self, nonce: &Nonce) -> StorageResult<bool>;
+
+    /// Returns whether the message is unspent, spent, or never existed.
+    /// Unlike combining [`Self::message_is_spent`] and [`Self::message_exists`],
+    /// it resolves the state from one view of the database.
+    fn message_status(&self, nonce: &Nonce) -> StorageResult<MessageStatus>;
+
+    /// Returns whether each message of the `nonces` is spent, preserving their order.
+    /// By default, every message is looked up separately.
+    fn messages_spent_status(&self, nonces: &[Nonce]) -> StorageResult<Vec<bool>> {
+        nonces
+            .iter()
+            .map(|nonce| self.message_is_spent(nonce))
+            .collect()
+    }
+
+    /// Returns the unspent messages that arrived at the `da_height`, ordered by the nonce.
+    ///
+    /// There is no index of messages by the DA height, so all messages are iterated
+    /// and filtered. The cost is proportional to the number of stored messages,
+    /// not to the number of messages at the `da_height`.
+    fn messages_at_da_height(
+        &self,
+        da_height: DaBlockHeight,
+    ) -> BoxedIter<'_, StorageResult<Message>> {
+        self.all_messages(None, IterDirection::Forward)
+            .filter(move |result| match result {
+                Ok(message) => message.da_height() == da_height,
+                Err(_) => true,
+            })
+            .into_boxed()
+    }
 }
 
 pub trait DatabaseRelayedTransactions {
@@ -157,6 +479,21 @@ pub trait DatabaseRelayedTransactions {
         &self,
         id: Bytes32,
     ) -> StorageResult<Option<RelayedTransactionStatus>>;
+
+    /// Returns the statuses of the relayed transaction ordered from the oldest
+    /// to the newest. See [`OffChainDatabase::relayed_tx_status_history`].
+    fn relayed_tx_status_history(
+        &self,
+        id: Bytes32,
+    ) -> BoxedIter<'_, StorageResult<RelayedTransactionStatus>>;
+
+    /// Returns the statuses of all relayed transactions ordered by the
+    /// transaction id. The `start` id is used as a cursor for the pagination.
+    fn relayed_tx_statuses(
+        &self,
+        start: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Bytes32, RelayedTransactionStatus)>>;
 }
 
 /// Trait that specifies all the getters required for contract.
@@ -164,17 +501,85 @@ pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
     + StorageInspect<ContractsAssets, Error = StorageError>
 {
+    /// Checks whether the contract is deployed without loading its bytecode.
+    fn contract_exists(&self, contract_id: &ContractId) -> StorageResult<bool>;
+
+    /// Returns the balances of the `contract` ordered by the asset id.
+    ///
+    /// The `start_asset` cursor is inclusive for the `IterDirection::Forward`. For the
+    /// `IterDirection::Reverse` it is exclusive: the iteration yields only balances
+    /// with asset ids strictly less than the cursor, in descending order.
+    /// Without the cursor, the reverse iteration starts from the highest asset id.
     fn contract_balances(
         &self,
         contract: ContractId,
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns up to `limit` balances of the `contract` in ascending asset order,
+    /// starting from the `start_asset` inclusively, together with the asset id
+    /// to start the next page from, or `None` if there are no more balances.
+    ///
+    /// Unlike [`Self::contract_balances`], no iterator is held between the pages.
+    fn contract_balances_page(
+        &self,
+        contract: ContractId,
+        start_asset: Option<AssetId>,
+        limit: usize,
+    ) -> StorageResult<(Vec<ContractBalance>, Option<AssetId>)> {
+        let mut balances =
+            self.contract_balances(contract, start_asset, IterDirection::Forward);
+        let page = balances
+            .by_ref()
+            .take(limit)
+            .collect::<StorageResult<Vec<_>>>()?;
+        let next_asset = balances.next().transpose()?.map(|balance| balance.asset_id);
+        Ok((page, next_asset))
+    }
+
+    /// Returns the number of assets owned by the contract.
+    fn contract_balance_count(&self, contract: ContractId) -> StorageResult<u64>;
+
+    /// Returns the balance of a single asset of the `contract` without iterating over
+    /// its other assets, or `None` if the contract doesn't own the asset.
+    fn contract_asset_balance(
+        &self,
+        contract: ContractId,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<u64>>;
+
+    /// Returns the value of the contract's storage slot at the `key`.
+    fn contract_state(
+        &self,
+        contract: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Option<Bytes32>>;
+
+    /// Returns the Merkle root over the storage slots of the `contract`,
+    /// against which the proofs of the individual slots are verified.
+    fn contract_state_root(&self, contract: ContractId) -> StorageResult<Bytes32>;
+
+    /// Returns the height of the block from the `tx_pointer` of the contract's
+    /// latest UTXO, or `None` if the contract doesn't exist.
+    ///
+    /// There is no separate index of the deployments, so it is only an approximation
+    /// of the deployment height: every transaction using the contract moves its
+    /// latest UTXO, and the height reflects the last such transaction rather than
+    /// the original deployment. Contracts from the snapshot point to the block
+    /// specified in the snapshot.
+    fn contract_deployed_height(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<Option<BlockHeight>>;
 }
 
 /// Trait that specifies all the getters required for chain metadata.
 pub trait DatabaseChain {
     fn da_height(&self) -> StorageResult<DaBlockHeight>;
+
+    /// Returns the approximate size in bytes of each on-chain table.
+    fn table_sizes(&self) -> StorageResult<Vec<(&'static str, u64)>>;
 }
 
 #[async_trait]
@@ -215,6 +620,13 @@ pub trait ConsensusModulePort: Send + Sync {
 
 /// Trait that specifies queries supported by the database.
 pub trait DatabaseMessageProof: Send + Sync {
+    /// The maximum size of the message data in bytes for which the proof is generated.
+    /// Messages with larger data are rejected before their leaf is hashed.
+    /// The size is not limited if it is `None`.
+    fn max_message_data_size(&self) -> Option<usize> {
+        None
+    }
+
     /// Gets the [`MerkleProof`] for the message block at `message_block_height` height
     /// relatively to the commit block where message block <= commit block.
     fn block_history_proof(
@@ -222,6 +634,43 @@ pub trait DatabaseMessageProof: Send + Sync {
         message_block_height: &BlockHeight,
         commit_block_height: &BlockHeight,
     ) -> StorageResult<MerkleProof>;
+
+    /// Gets the root of the block history Merkle tree at the `commit_block_height`,
+    /// which the proofs of [`Self::block_history_proof`] are verified against.
+    fn block_history_root(
+        &self,
+        commit_block_height: &BlockHeight,
+    ) -> StorageResult<Bytes32>;
+
+    /// Gets the [`MerkleProof`]s for each `(message_block_height, commit_block_height)`
+    /// pair of the `requests`, preserving their order. An error for one of the pairs
+    /// is returned in its place without aborting the rest of the batch.
+    fn block_history_proofs<'a>(
+        &'a self,
+        requests: &'a [(BlockHeight, BlockHeight)],
+    ) -> BoxedIter<'a, StorageResult<MerkleProof>> {
+        requests
+            .iter()
+            .map(|(message_block_height, commit_block_height)| {
+                self.block_history_proof(message_block_height, commit_block_height)
+            })
+            .into_boxed()
+    }
+
+    /// Gets the [`MerkleProof`]s for each of the `message_block_heights` relatively
+    /// to the same commit block, preserving their order.
+    fn block_history_proofs_for_commit(
+        &self,
+        commit_block_height: &BlockHeight,
+        message_block_heights: &[BlockHeight],
+    ) -> StorageResult<Vec<MerkleProof>> {
+        message_block_heights
+            .iter()
+            .map(|message_block_height| {
+                self.block_history_proof(message_block_height, commit_block_height)
+            })
+            .collect()
+    }
 }
 
 #[async_trait::async_trait]
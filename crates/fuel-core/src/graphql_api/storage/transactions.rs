@@ -21,6 +21,8 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         Bytes32,
+        TxId,
+        TxPointer,
     },
     fuel_types::BlockHeight,
     services::txpool::TransactionStatus,
@@ -217,6 +219,17 @@ impl From<OwnedTransactionIndexCursor> for Vec<u8> {
     }
 }
 
+/// The cursor of the transactions of an owner, pointing at one transaction.
+///
+/// Unlike a bare [`TxPointer`], it also carries the id of the transaction,
+/// so the pagination resumes precisely after it even if several transactions
+/// share the same [`TxPointer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OwnedTxCursor {
+    pub tx_pointer: TxPointer,
+    pub tx_id: TxId,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
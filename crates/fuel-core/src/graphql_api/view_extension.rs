@@ -7,6 +7,7 @@ use async_graphql::{
         NextPrepareRequest,
     },
     Request,
+    ServerError,
     ServerResult,
 };
 use std::sync::Arc;
@@ -36,8 +37,11 @@ impl Extension for ViewExtension {
         request: Request,
         next: NextPrepareRequest<'_>,
     ) -> ServerResult<Request> {
-        let database: &ReadDatabase = ctx.data_unchecked();
-        let view = database.view();
+        let database: &Arc<ReadDatabase> = ctx.data_unchecked();
+        let view = database
+            .view_with_retries()
+            .await
+            .map_err(|err| ServerError::new(err.to_string(), None))?;
         let request = request.data(view);
         next.run(ctx, request).await
     }
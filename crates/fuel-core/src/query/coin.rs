@@ -5,7 +5,6 @@ use crate::fuel_core_graphql_api::ports::{
 use fuel_core_storage::{
     iter::{
         BoxedIter,
-        IntoBoxedIter,
         IterDirection,
     },
     not_found,
@@ -28,13 +27,6 @@ pub trait CoinQueryData: Send + Sync {
         start_coin: Option<UtxoId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<UtxoId>>;
-
-    fn owned_coins(
-        &self,
-        owner: &Address,
-        start_coin: Option<UtxoId>,
-        direction: IterDirection,
-    ) -> BoxedIter<StorageResult<Coin>>;
 }
 
 impl<D: OnChainDatabase + OffChainDatabase + ?Sized> CoinQueryData for D {
@@ -56,15 +48,4 @@ impl<D: OnChainDatabase + OffChainDatabase + ?Sized> CoinQueryData for D {
     ) -> BoxedIter<StorageResult<UtxoId>> {
         self.owned_coins_ids(owner, start_coin, direction)
     }
-
-    fn owned_coins(
-        &self,
-        owner: &Address,
-        start_coin: Option<UtxoId>,
-        direction: IterDirection,
-    ) -> BoxedIter<StorageResult<Coin>> {
-        self.owned_coins_ids(owner, start_coin, direction)
-            .map(|res| res.and_then(|id| self.coin(id)))
-            .into_boxed()
-    }
 }
@@ -18,6 +18,7 @@ use fuel_core_storage::{
 use fuel_core_types::{
     fuel_types::{
         AssetId,
+        Bytes32,
         ContractId,
     },
     fuel_vm::Salt,
@@ -43,12 +44,19 @@ pub trait ContractQueryData: Send + Sync {
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_balance_count(&self, contract_id: ContractId) -> StorageResult<u64>;
+
+    fn contract_state(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Option<Bytes32>>;
 }
 
 impl<D: OnChainDatabase + OffChainDatabase + ?Sized> ContractQueryData for D {
     fn contract_id(&self, id: ContractId) -> StorageResult<ContractId> {
-        let contract_exists = self.storage::<ContractsRawCode>().contains_key(&id)?;
-        if contract_exists {
+        if self.contract_exists(&id)? {
             Ok(id)
         } else {
             Err(not_found!(ContractsRawCode))
@@ -75,10 +83,8 @@ impl<D: OnChainDatabase + OffChainDatabase + ?Sized> ContractQueryData for D {
         asset_id: AssetId,
     ) -> StorageResult<ContractBalance> {
         let amount = self
-            .storage::<ContractsAssets>()
-            .get(&(&contract_id, &asset_id).into())?
-            .ok_or(not_found!(ContractsAssets))?
-            .into_owned();
+            .contract_asset_balance(contract_id, &asset_id)?
+            .ok_or(not_found!(ContractsAssets))?;
 
         Ok(ContractBalance {
             owner: contract_id,
@@ -95,4 +101,16 @@ impl<D: OnChainDatabase + OffChainDatabase + ?Sized> ContractQueryData for D {
     ) -> BoxedIter<StorageResult<ContractBalance>> {
         self.contract_balances(contract_id, start_asset, direction)
     }
+
+    fn contract_balance_count(&self, contract_id: ContractId) -> StorageResult<u64> {
+        self.contract_balance_count(contract_id)
+    }
+
+    fn contract_state(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        self.contract_state(contract_id, key)
+    }
 }
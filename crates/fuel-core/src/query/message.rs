@@ -24,7 +24,6 @@ use fuel_core_storage::{
     tables::Messages,
     Error as StorageError,
     Result as StorageResult,
-    StorageAsRef,
 };
 use fuel_core_types::{
     blockchain::block::CompressedBlock,
@@ -34,7 +33,10 @@ use fuel_core_types::{
         MessageProof,
         MessageStatus,
     },
-    fuel_merkle::binary::in_memory::MerkleTree,
+    fuel_merkle::binary::{
+        self,
+        in_memory::MerkleTree,
+    },
     fuel_tx::{
         input::message::compute_message_id,
         Receipt,
@@ -50,7 +52,6 @@ use fuel_core_types::{
     services::txpool::TransactionStatus,
 };
 use itertools::Itertools;
-use std::borrow::Cow;
 
 #[cfg(test)]
 mod test;
@@ -81,10 +82,7 @@ pub trait MessageQueryData: Send + Sync {
 
 impl<D: OnChainDatabase + OffChainDatabase + ?Sized> MessageQueryData for D {
     fn message(&self, id: &Nonce) -> StorageResult<Message> {
-        self.storage::<Messages>()
-            .get(id)?
-            .ok_or(not_found!(Messages))
-            .map(Cow::into_owned)
+        self.message_by_nonce(id)?.ok_or(not_found!(Messages))
     }
 
     fn owned_message_ids(
@@ -139,7 +137,25 @@ where
     }
 }
 
+/// Errors of the message proof generation.
+#[derive(Debug, thiserror::Error)]
+pub enum MessageProofError {
+    #[error(
+        "The message data has size {size} bytes, which exceeds the maximum of {max} bytes"
+    )]
+    DataTooLarge { size: usize, max: usize },
+}
+
+impl From<MessageProofError> for StorageError {
+    fn from(error: MessageProofError) -> Self {
+        StorageError::Other(error.into())
+    }
+}
+
 /// Generate an output proof.
+///
+/// Fails with the [`MessageProofError::DataTooLarge`] if the data of the message
+/// exceeds the [`DatabaseMessageProof::max_message_data_size`].
 // TODO: Do we want to return `Option` here?
 pub fn message_proof<T: MessageProofData + ?Sized>(
     database: &T,
@@ -171,6 +187,15 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     };
     let data =
         data.ok_or(anyhow::anyhow!("Output message doesn't contain any `data`"))?;
+    if let Some(max) = database.max_message_data_size() {
+        if data.len() > max {
+            return Err(MessageProofError::DataTooLarge {
+                size: data.len(),
+                max,
+            }
+            .into())
+        }
+    }
 
     // Get the block id from the transaction status if it's ready.
     let message_block_height = match database
@@ -232,6 +257,21 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     }))
 }
 
+/// Verifies the block history `proof` of the `leaf` against the `root`.
+///
+/// The binary Merkle tree shape depends on the number of leaves, so the verification
+/// also requires `num_leaves`. For the `block_proof` of the [`MessageProof`],
+/// the `root` is the `prev_root` of the commit block, the `leaf` is the id of
+/// the message block, and `num_leaves` is the height of the commit block.
+pub fn verify_block_history_proof(
+    proof: &MerkleProof,
+    root: &Bytes32,
+    leaf: &Bytes32,
+    num_leaves: u64,
+) -> bool {
+    binary::verify(root, leaf, &proof.proof_set, proof.proof_index, num_leaves)
+}
+
 fn message_receipts_proof<T: MessageProofData + ?Sized>(
     database: &T,
     message_id: MessageId,
@@ -285,11 +325,5 @@ pub fn message_status<T: DatabaseMessages + ?Sized>(
     database: &T,
     message_nonce: Nonce,
 ) -> StorageResult<MessageStatus> {
-    if database.message_is_spent(&message_nonce)? {
-        Ok(MessageStatus::spent())
-    } else if database.message_exists(&message_nonce)? {
-        Ok(MessageStatus::unspent())
-    } else {
-        Ok(MessageStatus::not_found())
-    }
+    database.message_status(&message_nonce)
 }
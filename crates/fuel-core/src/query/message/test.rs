@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use std::ops::Deref;
 
 use fuel_core_types::{
@@ -63,11 +65,18 @@ mockall::mock! {
     }
 
     impl DatabaseMessageProof for ProofDataStorage {
+        fn max_message_data_size(&self) -> Option<usize>;
+
         fn block_history_proof(
             &self,
             message_block_height: &BlockHeight,
             commit_block_height: &BlockHeight,
         ) -> StorageResult<MerkleProof>;
+
+        fn block_history_root(
+            &self,
+            commit_block_height: &BlockHeight,
+        ) -> StorageResult<Bytes32>;
     }
 
     impl SimpleTransactionData for ProofDataStorage {
@@ -107,6 +116,8 @@ async fn can_build_message_proof() {
     let mut data = MockProofDataStorage::new();
     let mut count = 0;
 
+    data.expect_max_message_data_size().returning(|| None);
+
     data.expect_receipts().returning(move |txn_id| {
         if *txn_id == transaction_id {
             Ok(receipts.to_vec())
@@ -228,3 +239,84 @@ async fn can_build_message_proof() {
     );
     assert_eq!(proof.block_proof, block_proof);
 }
+
+#[test]
+fn message_proof__rejects_message_data_above_the_limit() {
+    // Given
+    let transaction_id = txn_id(1);
+    let nonce = Nonce::new([1; 32]);
+    let mut data = MockProofDataStorage::new();
+    data.expect_max_message_data_size().returning(|| Some(4));
+    data.expect_receipts().returning(move |_| {
+        Ok(vec![Receipt::MessageOut {
+            len: 5,
+            digest: Bytes32::new([0; 32]),
+            sender: Address::new([1; 32]),
+            recipient: Address::new([1; 32]),
+            amount: 0,
+            nonce,
+            data: Some(vec![0; 5]),
+        }])
+    });
+
+    // When
+    let result = message_proof(&data, transaction_id, nonce, 2u32.into());
+
+    // Then
+    let Err(StorageError::Other(error)) = result else {
+        panic!("Expected the message data to be rejected, got {result:?}");
+    };
+    assert!(matches!(
+        error.downcast_ref::<MessageProofError>(),
+        Some(MessageProofError::DataTooLarge { size: 5, max: 4 })
+    ));
+}
+
+fn block_history_tree(leaves: &[Bytes32]) -> MerkleTree {
+    let mut tree = MerkleTree::new();
+    for leaf in leaves {
+        tree.push(leaf.as_ref());
+    }
+    tree
+}
+
+#[test]
+fn verify_block_history_proof__accepts_valid_proof() {
+    // Given
+    let leaves: Vec<_> = (0..5).map(txn_id).collect();
+    let tree = block_history_tree(&leaves);
+    let root = Bytes32::from(tree.root());
+    let (_, proof_set) = tree.prove(2).unwrap();
+    let proof = MerkleProof {
+        proof_set,
+        proof_index: 2,
+    };
+
+    // When
+    let verified =
+        verify_block_history_proof(&proof, &root, &leaves[2], leaves.len() as u64);
+
+    // Then
+    assert!(verified);
+}
+
+#[test]
+fn verify_block_history_proof__rejects_tampered_leaf() {
+    // Given
+    let leaves: Vec<_> = (0..5).map(txn_id).collect();
+    let tree = block_history_tree(&leaves);
+    let root = Bytes32::from(tree.root());
+    let (_, proof_set) = tree.prove(2).unwrap();
+    let proof = MerkleProof {
+        proof_set,
+        proof_index: 2,
+    };
+    let tampered_leaf = txn_id(42);
+
+    // When
+    let verified =
+        verify_block_history_proof(&proof, &root, &tampered_leaf, leaves.len() as u64);
+
+    // Then
+    assert!(!verified);
+}
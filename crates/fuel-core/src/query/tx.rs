@@ -1,6 +1,9 @@
-use crate::fuel_core_graphql_api::ports::{
-    OffChainDatabase,
-    OnChainDatabase,
+use crate::fuel_core_graphql_api::{
+    database::ReadViewError,
+    ports::{
+        OffChainDatabase,
+        OnChainDatabase,
+    },
 };
 use fuel_core_storage::{
     iter::{
@@ -10,6 +13,7 @@ use fuel_core_storage::{
     },
     not_found,
     tables::Transactions,
+    Error as StorageError,
     Result as StorageResult,
     StorageAsRef,
 };
@@ -39,7 +43,9 @@ where
     fn transaction(&self, tx_id: &TxId) -> StorageResult<Transaction> {
         if let Some(tx) = self.storage::<Transactions>().get(tx_id)? {
             Ok(tx.into_owned())
-        } else if let Some(tx) = self.old_transaction(tx_id)? {
+        } else if let Some(tx) =
+            ReadViewError::none_without_history(self.old_transaction(tx_id))?
+        {
             Ok(tx)
         } else {
             Err(not_found!(Transactions))
@@ -61,6 +67,21 @@ where
 pub trait TransactionQueryData: Send + Sync + SimpleTransactionData {
     fn status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
 
+    /// Returns the status of the transaction together with whether it is terminal,
+    /// or `None` if the transaction is unknown.
+    ///
+    /// Every status except the `Submitted` is terminal and doesn't change anymore,
+    /// the same rule that closes the status change subscription.
+    fn tx_status_detail(
+        &self,
+        tx_id: &TxId,
+    ) -> StorageResult<Option<(TransactionStatus, bool)>>;
+
+    /// Returns the transactions of the `owner` together with their bodies.
+    ///
+    /// The bodies are taken from the on-chain database or, for transactions from before
+    /// the regenesis, from the old transactions. The transactions without a body in
+    /// either of them are skipped instead of failing the whole page.
     fn owned_transactions(
         &self,
         owner: Address,
@@ -77,6 +98,20 @@ where
         self.tx_status(tx_id)
     }
 
+    fn tx_status_detail(
+        &self,
+        tx_id: &TxId,
+    ) -> StorageResult<Option<(TransactionStatus, bool)>> {
+        match self.status(tx_id) {
+            Ok(status) => {
+                let is_terminal = !matches!(status, TransactionStatus::Submitted { .. });
+                Ok(Some((status, is_terminal)))
+            }
+            Err(StorageError::NotFound(_, _)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     fn owned_transactions(
         &self,
         owner: Address,
@@ -84,12 +119,16 @@ where
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
         self.owned_transactions_ids(owner, start, direction)
-            .map(|result| {
-                result.and_then(|(tx_pointer, tx_id)| {
-                    let tx = self.transaction(&tx_id)?;
-
-                    Ok((tx_pointer, tx))
-                })
+            .filter_map(|result| {
+                let (tx_pointer, tx_id) = match result {
+                    Ok(ids) => ids,
+                    Err(e) => return Some(Err(e)),
+                };
+                match self.transaction(&tx_id) {
+                    Ok(tx) => Some(Ok((tx_pointer, tx))),
+                    Err(StorageError::NotFound(_, _)) => None,
+                    Err(e) => Some(Err(e)),
+                }
             })
             .into_boxed()
     }
@@ -141,21 +141,21 @@ where
             }
 
             let entries = entries(&start, direction)?;
-            let mut has_previous_page = false;
+            // The `start` cursor is the key of an entry of the previous page, so the previous
+            // page exists whenever it is set. Some `entries` begin after the `start` instead of
+            // at it, so whether the `start` was yielded doesn't tell it.
+            // TODO: `entries` should return information about `has_previous_page` for wild
+            //  queries
+            let has_previous_page = start.is_some();
             let mut has_next_page = false;
 
             // TODO: Add support of `skip` field for pages with huge list of entities with
             //  the same `SchemaKey`.
             let entries = entries.skip_while(|result| {
                 if let Ok((key, _)) = result {
-                    // TODO: `entries` should return information about `has_previous_page` for wild
-                    //  queries
                     if let Some(start) = start.as_ref() {
                         // Skip until start + 1
-                        if key == start {
-                            has_previous_page = true;
-                            return true
-                        }
+                        return key == start
                     }
                 }
                 false
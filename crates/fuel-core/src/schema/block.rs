@@ -102,15 +102,9 @@ impl Block {
 
     async fn consensus(&self, ctx: &Context<'_>) -> async_graphql::Result<Consensus> {
         let query: &ReadView = ctx.data_unchecked();
-        let height = self.0.header().height();
-        match query.consensus(height) {
-            Ok(consensus) => Ok(consensus.try_into()?),
-            Err(err) => {
-                // Fallback to pre-regenesis data
-                let consensus = query.old_block_consensus(*height).map_err(|_| err)?;
-                Ok(consensus.try_into()?)
-            }
-        }
+        let height = *self.0.header().height();
+        let consensus = query.block_consensus(height)?;
+        Ok(consensus.try_into()?)
     }
 
     async fn transactions(
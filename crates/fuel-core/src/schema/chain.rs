@@ -1,13 +1,13 @@
 use crate::{
     fuel_core_graphql_api::{
         api_service::ConsensusProvider,
-        database::ReadView,
+        database::{
+            Heights,
+            ReadView,
+        },
     },
     graphql_api::Config,
-    query::{
-        BlockQueryData,
-        ChainQueryData,
-    },
+    query::SimpleBlockData,
     schema::{
         block::Block,
         scalars::{
@@ -34,7 +34,7 @@ use std::{
     sync::Arc,
 };
 
-pub struct ChainInfo;
+pub struct ChainInfo(Heights);
 pub struct ConsensusParameters(Arc<fuel_tx::ConsensusParameters>);
 pub struct TxParameters(fuel_tx::TxParameters);
 pub struct PredicateParameters(fuel_tx::PredicateParameters);
@@ -819,18 +819,12 @@ impl ChainInfo {
     async fn latest_block(&self, ctx: &Context<'_>) -> async_graphql::Result<Block> {
         let query: &ReadView = ctx.data_unchecked();
 
-        let latest_block = query.latest_block()?.into();
+        let latest_block = query.block(&self.0.latest)?.into();
         Ok(latest_block)
     }
 
-    async fn da_height(&self, ctx: &Context<'_>) -> U64 {
-        let query: &ReadView = ctx.data_unchecked();
-
-        let height = query
-            .da_height()
-            .expect("The blockchain always should have genesis block");
-
-        height.0.into()
+    async fn da_height(&self) -> U64 {
+        self.0.da.0.into()
     }
 
     async fn consensus_parameters(
@@ -858,7 +852,8 @@ pub struct ChainQuery;
 
 #[Object]
 impl ChainQuery {
-    async fn chain(&self) -> ChainInfo {
-        ChainInfo
+    async fn chain(&self, ctx: &Context<'_>) -> async_graphql::Result<ChainInfo> {
+        let query: &ReadView = ctx.data_unchecked();
+        Ok(ChainInfo(query.heights()?))
     }
 }
@@ -156,6 +156,16 @@ impl CoinQuery {
         query.coin(utxo_id.0).into_api_result()
     }
 
+    /// Returns true if the coin with `utxo_id` exists and is unspent.
+    async fn coin_exists(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the coin")] utxo_id: UtxoId,
+    ) -> async_graphql::Result<bool> {
+        let query: &ReadView = ctx.data_unchecked();
+        Ok(query.coin_exists(&utxo_id.0)?)
+    }
+
     /// Gets all unspent coins of some `owner` maybe filtered with by `asset_id` per page.
     async fn coins(
         &self,
@@ -172,16 +182,21 @@ impl CoinQuery {
             let coins = query
                 .owned_coins(&owner, (*start).map(Into::into), direction)
                 .filter_map(|result| {
-                    if let (Ok(coin), Some(filter_asset_id)) = (&result, &filter.asset_id)
+                    if let (Ok((_, coin)), Some(filter_asset_id)) =
+                        (&result, &filter.asset_id)
                     {
-                        if coin.asset_id != filter_asset_id.0 {
+                        if coin.asset_id() != &filter_asset_id.0 {
                             return None
                         }
                     }
 
                     Some(result)
                 })
-                .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
+                .map(|res| {
+                    res.map(|(utxo_id, coin)| {
+                        (utxo_id.into(), coin.uncompress(utxo_id).into())
+                    })
+                });
 
             Ok(coins)
         })
@@ -6,6 +6,7 @@ use crate::{
     query::ContractQueryData,
     schema::scalars::{
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         Salt,
@@ -70,6 +71,18 @@ impl ContractQuery {
         let query: &ReadView = ctx.data_unchecked();
         query.contract_id(id.0).into_api_result()
     }
+
+    /// Returns the value of the contract's storage slot at the `key`,
+    /// or `null` if the slot is not set.
+    async fn contract_slot(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        key: Bytes32,
+    ) -> async_graphql::Result<Option<Bytes32>> {
+        let query: &ReadView = ctx.data_unchecked();
+        Ok(query.contract_state(contract.0, key.0)?.map(Into::into))
+    }
 }
 
 pub struct ContractBalance(graphql_api::ContractBalance);
@@ -155,6 +168,19 @@ impl ContractBalanceQuery {
         })
         .await
     }
+
+    /// Returns the number of assets owned by the contract.
+    async fn contract_balance_count(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+    ) -> async_graphql::Result<U64> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .contract_balance_count(contract.into())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
 }
 
 impl From<graphql_api::ContractBalance> for ContractBalance {
@@ -12,9 +12,11 @@ use super::{
 use crate::{
     fuel_core_graphql_api::{
         database::ReadView,
-        ports::OffChainDatabase,
+        ports::{
+            self,
+            OffChainDatabase,
+        },
     },
-    graphql_api::IntoApiResult,
     query::MessageQueryData,
     schema::scalars::{
         BlockId,
@@ -31,7 +33,9 @@ use async_graphql::{
     Enum,
     Object,
 };
+use fuel_core_storage::iter::IntoBoxed;
 use fuel_core_types::entities;
+use itertools::Itertools;
 
 pub struct Message(pub(crate) entities::relayer::message::Message);
 
@@ -74,7 +78,8 @@ impl MessageQuery {
     ) -> async_graphql::Result<Option<Message>> {
         let query: &ReadView = ctx.data_unchecked();
         let nonce = nonce.0;
-        query.message(&nonce).into_api_result()
+        // `MessageQueryData` also defines `all_messages`, so the port trait isn't imported.
+        Ok(ports::DatabaseMessages::message_by_nonce(query, &nonce)?.map(Into::into))
     }
 
     async fn messages(
@@ -101,7 +106,10 @@ impl MessageQuery {
                 };
 
                 let messages = if let Some(owner) = owner {
-                    query.owned_messages(&owner.0, start, direction)
+                    query
+                        .owned_messages(&owner.0, start, direction)
+                        .map_ok(|(_, message)| message)
+                        .into_boxed()
                 } else {
                     query.all_messages(start, direction)
                 };
@@ -118,6 +126,19 @@ impl MessageQuery {
         .await
     }
 
+    /// Returns the number of messages owned by the `owner`.
+    async fn owned_message_count(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "address of the owner")] owner: Address,
+    ) -> async_graphql::Result<U64> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .owned_message_count(&owner.0)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
     async fn message_proof(
         &self,
         ctx: &Context<'_>,
@@ -51,6 +51,8 @@ pub struct SharedState {
     pub graph_ql: crate::fuel_core_graphql_api::api_service::SharedState,
     /// The underlying database.
     pub database: CombinedDatabase,
+    /// The progress of the genesis import.
+    pub genesis_progress: genesis::GenesisProgress,
     /// Subscribe to new block production.
     pub block_importer: BlockImporterAdapter,
     /// The config of the service.
@@ -228,6 +230,7 @@ impl RunnableService for Task {
                     watcher.clone(),
                     &self.shared.config,
                     &self.shared.database,
+                    self.shared.genesis_progress.clone(),
                 )
                 .await?;
 
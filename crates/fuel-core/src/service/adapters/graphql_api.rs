@@ -22,7 +22,13 @@ use crate::{
 };
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+    },
+    Result as StorageResult,
+};
 use fuel_core_txpool::{
     service::TxStatusMessage,
     types::TxId,
@@ -96,6 +102,32 @@ impl DatabaseMessageProof for Database {
     ) -> StorageResult<MerkleProof> {
         Database::block_history_proof(self, message_block_height, commit_block_height)
     }
+
+    fn block_history_root(
+        &self,
+        commit_block_height: &BlockHeight,
+    ) -> StorageResult<Bytes32> {
+        Database::block_history_root(self, commit_block_height)
+    }
+
+    fn block_history_proofs<'a>(
+        &'a self,
+        requests: &'a [(BlockHeight, BlockHeight)],
+    ) -> BoxedIter<'a, StorageResult<MerkleProof>> {
+        Database::block_history_proofs(self, requests).into_boxed()
+    }
+
+    fn block_history_proofs_for_commit(
+        &self,
+        commit_block_height: &BlockHeight,
+        message_block_heights: &[BlockHeight],
+    ) -> StorageResult<Vec<MerkleProof>> {
+        Database::block_history_proofs_for_commit(
+            self,
+            commit_block_height,
+            message_block_heights,
+        )
+    }
 }
 
 #[async_trait]
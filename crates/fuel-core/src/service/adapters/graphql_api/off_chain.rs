@@ -96,17 +96,30 @@ impl OffChainDatabase for Database<OffChain> {
             .into_boxed()
     }
 
+    fn owned_message_count(&self, owner: &Address) -> StorageResult<u64> {
+        self.owned_message_count(owner)
+    }
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        // The storage iterator includes the `start` key in both directions,
+        // while in the reverse direction the cursor is exclusive.
+        let excluded = match direction {
+            IterDirection::Forward => None,
+            IterDirection::Reverse => start,
+        };
         let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
             block_height: tx_pointer.block_height(),
             tx_idx: tx_pointer.tx_index(),
         });
         self.owned_transactions(owner, start, Some(direction))
+            .skip_while(move |result| {
+                matches!(result, Ok((tx_pointer, _)) if Some(*tx_pointer) == excluded)
+            })
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
@@ -121,6 +134,43 @@ impl OffChainDatabase for Database<OffChain> {
         Ok(salt)
     }
 
+    /// The contracts info is sorted by the contract id, so all salts are
+    /// resolved by one scan from the smallest requested id up to the largest.
+    fn contract_salts(
+        &self,
+        contract_ids: &[ContractId],
+    ) -> BoxedIter<'_, StorageResult<(ContractId, Salt)>> {
+        let mut contract_ids = contract_ids.to_vec();
+        contract_ids.sort();
+        contract_ids.dedup();
+        let (Some(first), Some(last)) =
+            (contract_ids.first().copied(), contract_ids.last().copied())
+        else {
+            return core::iter::empty().into_boxed()
+        };
+
+        let mut requested = contract_ids.into_iter().peekable();
+        self.iter_all_by_start::<ContractsInfo>(
+            Some(&first),
+            Some(IterDirection::Forward),
+        )
+        .take_while(move |result| {
+            result
+                .as_ref()
+                .map_or(true, |(contract_id, _)| *contract_id <= last)
+        })
+        .filter_map(move |result| match result {
+            Ok((contract_id, info)) => {
+                while requested.next_if(|id| *id < contract_id).is_some() {}
+                requested
+                    .next_if_eq(&contract_id)
+                    .map(|_| Ok((contract_id, *info.salt())))
+            }
+            Err(err) => Some(Err(err)),
+        })
+        .into_boxed()
+    }
+
     fn old_blocks(
         &self,
         height: Option<BlockHeight>,
@@ -131,6 +181,10 @@ impl OffChainDatabase for Database<OffChain> {
             .into_boxed()
     }
 
+    fn old_block_exists(&self, height: BlockHeight) -> StorageResult<bool> {
+        self.storage_as_ref::<OldFuelBlocks>().contains_key(&height)
+    }
+
     fn old_block_consensus(&self, height: BlockHeight) -> StorageResult<Consensus> {
         Ok(self
             .storage_as_ref::<OldFuelBlockConsensus>()
@@ -145,6 +199,22 @@ impl OffChainDatabase for Database<OffChain> {
             .map(|tx| tx.map(|tx| tx.into_owned()))
     }
 
+    fn old_transaction_at(
+        &self,
+        height: BlockHeight,
+        tx_index: u16,
+    ) -> StorageResult<Option<Transaction>> {
+        let block = self
+            .storage_as_ref::<OldFuelBlocks>()
+            .get(&height)?
+            .ok_or(not_found!(OldFuelBlocks))?;
+
+        match block.transactions().get(usize::from(tx_index)) {
+            Some(tx_id) => self.old_transaction(tx_id),
+            None => Ok(None),
+        }
+    }
+
     fn relayed_tx_status(
         &self,
         id: Bytes32,
@@ -156,6 +226,18 @@ impl OffChainDatabase for Database<OffChain> {
             .map(|cow| cow.into_owned());
         Ok(status)
     }
+
+    fn relayed_tx_statuses(
+        &self,
+        start: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Bytes32, RelayedTransactionStatus)>> {
+        self.iter_all_by_start::<RelayedTransactionStatuses>(
+            start.as_ref(),
+            Some(direction),
+        )
+        .into_boxed()
+    }
 }
 
 impl Transactional for Database<OffChain> {
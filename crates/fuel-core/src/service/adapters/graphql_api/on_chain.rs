@@ -3,6 +3,7 @@ use crate::{
     fuel_core_graphql_api::ports::{
         DatabaseBlocks,
         DatabaseChain,
+        DatabaseCoins,
         DatabaseContracts,
         DatabaseMessages,
         OnChainDatabase,
@@ -17,9 +18,16 @@ use fuel_core_storage::{
         IteratorOverTable,
     },
     not_found,
-    tables::FuelBlocks,
+    tables::{
+        Coins,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        FuelBlocks,
+        Messages,
+    },
     Error as StorageError,
     Result as StorageResult,
+    StorageAsRef,
 };
 use fuel_core_txpool::types::ContractId;
 use fuel_core_types::{
@@ -27,15 +35,26 @@ use fuel_core_types::{
         block::CompressedBlock,
         primitives::DaBlockHeight,
     },
-    entities::relayer::message::Message,
-    fuel_tx::AssetId,
+    entities::{
+        coins::coin::CompressedCoin,
+        relayer::message::{
+            Message,
+            MessageStatus,
+        },
+    },
+    fuel_tx::{
+        AssetId,
+        UtxoId,
+    },
     fuel_types::{
         BlockHeight,
+        Bytes32,
         Nonce,
     },
     services::graphql_api::ContractBalance,
 };
 use itertools::Itertools;
+use std::borrow::Cow;
 
 impl DatabaseBlocks for Database {
     fn blocks(
@@ -61,6 +80,17 @@ impl DatabaseBlocks for Database {
     }
 }
 
+impl DatabaseCoins for Database {
+    fn all_coins(
+        &self,
+        start: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(UtxoId, CompressedCoin)>> {
+        self.iter_all_by_start::<Coins>(start.as_ref(), Some(direction))
+            .into_boxed()
+    }
+}
+
 impl DatabaseMessages for Database {
     fn all_messages(
         &self,
@@ -72,6 +102,21 @@ impl DatabaseMessages for Database {
             .into_boxed()
     }
 
+    fn unspent_messages(
+        &self,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Message>> {
+        self.unspent_messages(start_message_id, Some(direction))
+            .into_boxed()
+    }
+
+    fn message_by_nonce(&self, nonce: &Nonce) -> StorageResult<Option<Message>> {
+        self.storage::<Messages>()
+            .get(nonce)
+            .map(|message| message.map(Cow::into_owned))
+    }
+
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.message_is_spent(nonce)
     }
@@ -79,16 +124,33 @@ impl DatabaseMessages for Database {
     fn message_exists(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.message_exists(nonce)
     }
+
+    fn message_status(&self, nonce: &Nonce) -> StorageResult<MessageStatus> {
+        self.message_status(nonce)
+    }
 }
 
 impl DatabaseContracts for Database {
+    fn contract_exists(&self, contract_id: &ContractId) -> StorageResult<bool> {
+        self.storage::<ContractsRawCode>().contains_key(contract_id)
+    }
+
     fn contract_balances(
         &self,
         contract: ContractId,
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>> {
+        // The storage iterator includes the `start_asset` in both directions,
+        // while in the reverse direction the cursor is exclusive.
+        let excluded = match direction {
+            IterDirection::Forward => None,
+            IterDirection::Reverse => start_asset,
+        };
         self.filter_contract_balances(contract, start_asset, Some(direction))
+            .skip_while(move |result| {
+                matches!(result, Ok(entry) if Some(*entry.key.asset_id()) == excluded)
+            })
             .map_ok(|entry| ContractBalance {
                 owner: *entry.key.contract_id(),
                 amount: entry.value,
@@ -97,6 +159,41 @@ impl DatabaseContracts for Database {
             .map(|res| res.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn contract_balance_count(&self, contract: ContractId) -> StorageResult<u64> {
+        self.contract_balance_count(contract)
+    }
+
+    fn contract_asset_balance(
+        &self,
+        contract: ContractId,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<u64>> {
+        self.contract_asset_balance(contract, asset_id)
+    }
+
+    fn contract_state(
+        &self,
+        contract: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        self.contract_state(contract, key)
+    }
+
+    fn contract_state_root(&self, contract: ContractId) -> StorageResult<Bytes32> {
+        self.contract_state_root(&contract)
+    }
+
+    fn contract_deployed_height(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<Option<BlockHeight>> {
+        let height = self
+            .storage::<ContractsLatestUtxo>()
+            .get(contract_id)?
+            .map(|utxo_info| utxo_info.tx_pointer().block_height());
+        Ok(height)
+    }
 }
 
 impl DatabaseChain for Database {
@@ -105,6 +202,10 @@ impl DatabaseChain for Database {
             .map(|block| block.header().da_height)
             .ok_or(not_found!("DaBlockHeight"))
     }
+
+    fn table_sizes(&self) -> StorageResult<Vec<(&'static str, u64)>> {
+        self.table_sizes()
+    }
 }
 
 impl OnChainDatabase for Database {}
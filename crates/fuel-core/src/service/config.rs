@@ -36,7 +36,15 @@ pub use fuel_core_consensus_module::RelayerConsensusConfig;
 pub use fuel_core_importer;
 pub use fuel_core_poa::Trigger;
 
-use crate::combined_database::CombinedDatabaseConfig;
+use crate::{
+    combined_database::CombinedDatabaseConfig,
+    graphql_api::database::ReadViewConfig,
+    service::genesis::{
+        GenesisValidators,
+        HandlerConfig,
+        ImportScope,
+    },
+};
 
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -44,6 +52,16 @@ pub struct Config {
     pub api_request_timeout: Duration,
     pub combined_db_config: CombinedDatabaseConfig,
     pub snapshot_reader: SnapshotReader,
+    /// Verifies after the genesis import that no coin from the snapshot uses
+    /// the nonce of a message from the snapshot as its transaction id, and that
+    /// every contract with the latest UTXO has the bytecode.
+    pub genesis_cross_checks: bool,
+    /// The validators run for every group of the snapshot during the genesis import.
+    pub genesis_validators: GenesisValidators,
+    /// The tables of the snapshot imported at genesis.
+    pub genesis_import_scope: ImportScope,
+    /// The settings of the handlers importing the on-chain tables at genesis.
+    pub genesis_handler_config: HandlerConfig,
     /// When `true`:
     /// - Enables manual block production.
     /// - Enables debugger endpoint.
@@ -73,6 +91,10 @@ pub struct Config {
     pub time_until_synced: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// The configuration of the database views used by the GraphQL reads: the retries
+    /// of the reads failed because of the database, the maximum skew between
+    /// the on-chain and off-chain heights, and the limits of the message proof cache.
+    pub read_view: ReadViewConfig,
 }
 
 impl Config {
@@ -127,6 +149,10 @@ impl Config {
             utxo_validation,
             native_executor_version: Some(native_executor_version),
             snapshot_reader,
+            genesis_cross_checks: false,
+            genesis_validators: GenesisValidators::default(),
+            genesis_import_scope: ImportScope::all(),
+            genesis_handler_config: HandlerConfig::default(),
             block_production: Trigger::Instant,
             vm: Default::default(),
             txpool: fuel_core_txpool::Config {
@@ -153,6 +179,7 @@ impl Config {
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             query_log_threshold_time: Duration::from_secs(2),
+            read_view: ReadViewConfig::default(),
         }
     }
 
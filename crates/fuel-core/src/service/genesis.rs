@@ -6,6 +6,7 @@ use crate::{
             on_chain::OnChain,
         },
         genesis_progress::GenesisMetadata,
+        Database,
     },
     service::config::Config,
 };
@@ -14,7 +15,11 @@ use fuel_core_services::StateWatcher;
 use fuel_core_storage::{
     iter::IteratorOverTable,
     tables::{
+        Coins,
         ConsensusParametersVersions,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        Messages,
         StateTransitionBytecodeVersions,
         UploadedBytecodes,
     },
@@ -23,7 +28,11 @@ use fuel_core_storage::{
         IntoTransaction,
         ReadTransaction,
     },
+    Error as StorageError,
+    Result as StorageResult,
     StorageAsMut,
+    StorageAsRef,
+    StorageInspect,
 };
 use fuel_core_types::{
     self,
@@ -44,7 +53,15 @@ use fuel_core_types::{
         SealedBlock,
     },
     fuel_crypto::Hasher,
-    fuel_types::Bytes32,
+    fuel_tx::{
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        Bytes32,
+        ContractId,
+        Nonce,
+    },
     fuel_vm::UploadedBytecode,
     services::block_importer::{
         ImportResult,
@@ -57,26 +74,62 @@ mod exporter;
 mod importer;
 mod task_manager;
 
-pub use exporter::Exporter;
+pub use exporter::{
+    ExportTable,
+    Exporter,
+};
+pub use importer::{
+    CoinTxPointerHeight,
+    CoinTxPointerPolicy,
+    ContractCodeSize,
+    ContractStateCommits,
+    GenesisImportReport,
+    GenesisMetrics,
+    GenesisProgress,
+    GenesisProgressEvent,
+    GenesisValidationContext,
+    GenesisValidator,
+    GenesisValidators,
+    GroupValidationError,
+    HandlerConfig,
+    ImportOptions,
+    ImportScope,
+    TableMetrics,
+    TableProgress,
+    ValidationReport,
+};
 
 use self::importer::SnapshotImporter;
 
 /// Performs the importing of the genesis block from the snapshot.
+/// The progress of the import is reported into the `genesis_progress`.
 pub async fn execute_genesis_block(
     watcher: StateWatcher,
     config: &Config,
     db: &CombinedDatabase,
+    genesis_progress: GenesisProgress,
 ) -> anyhow::Result<UncommittedImportResult<Changes>> {
     let genesis_block = create_genesis_block(config);
     tracing::info!("Genesis block created: {:?}", genesis_block.header());
 
-    SnapshotImporter::import(
+    let options = ImportOptions {
+        progress: genesis_progress,
+        ..import_options(config)
+    };
+    let report = SnapshotImporter::import(
         db.clone(),
         genesis_block.clone(),
         config.snapshot_reader.clone(),
+        options,
         watcher,
     )
     .await?;
+    tracing::debug!("Genesis import report: {report:?}");
+
+    if config.genesis_cross_checks {
+        ensure_coins_dont_reuse_message_nonces(db.on_chain())?;
+        ensure_contracts_latest_utxos_have_code(db.on_chain())?;
+    }
 
     let genesis_progress_on_chain: Vec<String> = db
         .on_chain()
@@ -148,12 +201,107 @@ pub async fn execute_genesis_block(
     Ok(result)
 }
 
+/// Returns the `UtxoId` of the coin derived from the message with the `nonce`,
+/// if such a coin is in the `storage`. The coin derived from the message has
+/// the nonce as the transaction id and the zero output index. Both of them
+/// in the snapshot mean that the same retryable message is counted twice.
+fn coin_derived_from_message<S>(
+    storage: &S,
+    nonce: &Nonce,
+) -> StorageResult<Option<UtxoId>>
+where
+    S: StorageInspect<Coins, Error = StorageError>,
+{
+    let utxo_id = UtxoId::new(TxId::from(**nonce), 0);
+    let exists = storage.storage::<Coins>().contains_key(&utxo_id)?;
+    Ok(exists.then_some(utxo_id))
+}
+
+/// Returns an error if the coin derived from any imported message is imported too,
+/// see [`coin_derived_from_message`].
+fn ensure_coins_dont_reuse_message_nonces(db: &Database) -> anyhow::Result<()> {
+    let mut overlapping = vec![];
+    for result in db.iter_all::<Messages>(None) {
+        let (nonce, _) = result?;
+        if let Some(utxo_id) = coin_derived_from_message(db, &nonce)? {
+            overlapping.push(format!("{nonce}: {utxo_id}"));
+        }
+    }
+
+    if !overlapping.is_empty() {
+        anyhow::bail!(
+            "The coins use the nonces of the messages from the snapshot \
+            as their transaction ids:\n{}",
+            overlapping.join("\n")
+        );
+    }
+    Ok(())
+}
+
+/// Returns an error listing the contracts with the latest UTXO but without
+/// the bytecode. It happens if the tables of the snapshot are out of sync.
+fn ensure_contracts_latest_utxos_have_code(db: &Database) -> anyhow::Result<()> {
+    let mut dangling: Vec<ContractId> = vec![];
+    for result in db.iter_all::<ContractsLatestUtxo>(None) {
+        let (contract_id, _) = result?;
+        if !db
+            .storage::<ContractsRawCode>()
+            .contains_key(&contract_id)?
+        {
+            dangling.push(contract_id);
+        }
+    }
+
+    if !dangling.is_empty() {
+        anyhow::bail!(
+            "The contracts {dangling:?} have the latest UTXO in the snapshot \
+            but no bytecode"
+        );
+    }
+    Ok(())
+}
+
+/// Runs all checks of the genesis import against the snapshot without
+/// writing anything into the database. Returns errors of all invalid groups.
+pub async fn validate_genesis_snapshot(
+    watcher: StateWatcher,
+    config: &Config,
+    db: &CombinedDatabase,
+) -> anyhow::Result<ValidationReport> {
+    let genesis_block = create_genesis_block(config);
+
+    SnapshotImporter::validate(
+        db.clone(),
+        genesis_block,
+        config.snapshot_reader.clone(),
+        import_options(config),
+        watcher,
+    )
+    .await
+}
+
+/// Returns the options of the genesis import from the `config`, without the progress.
+fn import_options(config: &Config) -> ImportOptions {
+    ImportOptions {
+        validators: config.genesis_validators.clone(),
+        scope: config.genesis_import_scope.clone(),
+        handler_config: config.genesis_handler_config,
+        progress: GenesisProgress::default(),
+    }
+}
+
 #[cfg(feature = "test-helpers")]
 pub async fn execute_and_commit_genesis_block(
     config: &Config,
     db: &CombinedDatabase,
 ) -> anyhow::Result<()> {
-    let result = execute_genesis_block(StateWatcher::default(), config, db).await?;
+    let result = execute_genesis_block(
+        StateWatcher::default(),
+        config,
+        db,
+        GenesisProgress::default(),
+    )
+    .await?;
     let importer = fuel_core_importer::Importer::new(
         config
             .snapshot_reader
@@ -250,6 +398,7 @@ mod tests {
         },
     };
     use fuel_core_chain_config::{
+        ChainConfig,
         CoinConfig,
         ContractConfig,
         LastBlockConfig,
@@ -268,8 +417,14 @@ mod tests {
     };
     use fuel_core_types::{
         blockchain::primitives::DaBlockHeight,
-        entities::coins::coin::Coin,
-        fuel_tx::UtxoId,
+        entities::{
+            coins::coin::Coin,
+            contract::ContractUtxoInfo,
+        },
+        fuel_tx::{
+            TxId,
+            UtxoId,
+        },
         fuel_types::{
             Address,
             AssetId,
@@ -572,6 +727,130 @@ mod tests {
         assert!(init_result.is_err())
     }
 
+    #[tokio::test]
+    async fn coin_tx_pointer_is_clamped_to_genesis_height_if_configured() {
+        let state = StateConfig {
+            coins: vec![CoinConfig {
+                // set txpointer height > genesis height
+                tx_pointer_block_height: BlockHeight::from(11u32),
+                amount: 10,
+                ..Default::default()
+            }],
+            last_block: Some(LastBlockConfig {
+                block_height: BlockHeight::from(9u32),
+                state_transition_version: 0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut service_config = Config::local_node_with_state_config(state);
+        service_config.genesis_handler_config.coin_tx_pointer_policy =
+            CoinTxPointerPolicy::ClampToGenesis;
+
+        let db = CombinedDatabase::default();
+        let task = Task::new(db, service_config).unwrap();
+        let init_result = task.into_task(&Default::default(), ()).await;
+
+        assert!(init_result.is_ok())
+    }
+
+    #[tokio::test]
+    async fn coin_amount_cant_be_zero() {
+        let state = StateConfig {
+            coins: vec![CoinConfig {
+                amount: 0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let service_config = Config::local_node_with_state_config(state);
+
+        let db = CombinedDatabase::default();
+        let task = Task::new(db, service_config).unwrap();
+        let init_result = task.into_task(&Default::default(), ()).await;
+
+        assert!(init_result.is_err())
+    }
+
+    #[tokio::test]
+    async fn coin_utxo_ids_cant_repeat_within_group() {
+        let coin = CoinConfig {
+            amount: 10,
+            ..Default::default()
+        };
+        let state = StateConfig {
+            coins: vec![coin.clone(), coin],
+            ..Default::default()
+        };
+        let service_config = Config::local_node_with_state_config(state);
+
+        let db = CombinedDatabase::default();
+        let task = Task::new(db, service_config).unwrap();
+        let init_result = task.into_task(&Default::default(), ()).await;
+
+        assert!(init_result.is_err())
+    }
+
+    #[tokio::test]
+    async fn invalid_messages_are_reported_together() {
+        let mut rng = StdRng::seed_from_u64(10);
+
+        let duplicated = MessageConfig {
+            da_height: DaBlockHeight(0),
+            ..MessageConfig::randomize(&mut rng)
+        };
+        let too_high = MessageConfig {
+            da_height: DaBlockHeight(11),
+            ..MessageConfig::randomize(&mut rng)
+        };
+        let state = StateConfig {
+            messages: vec![duplicated.clone(), too_high.clone(), duplicated.clone()],
+            last_block: Some(LastBlockConfig {
+                da_block_height: DaBlockHeight(10),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let service_config = Config::local_node_with_state_config(state);
+
+        let db = CombinedDatabase::default();
+        let task = Task::new(db, service_config).unwrap();
+        let init_result = task.into_task(&Default::default(), ()).await;
+
+        let error = format!("{:?}", init_result.err().expect("Expected an error"));
+        assert!(error.contains(&duplicated.nonce.to_string()));
+        assert!(error.contains(&too_high.nonce.to_string()));
+    }
+
+    #[tokio::test]
+    async fn message_nonce_cant_collide_with_coin_utxo_id() {
+        let mut rng = StdRng::seed_from_u64(10);
+
+        let message = MessageConfig {
+            da_height: DaBlockHeight(0),
+            ..MessageConfig::randomize(&mut rng)
+        };
+        let coin = CoinConfig {
+            tx_id: TxId::from(*message.nonce),
+            output_index: 0,
+            tx_pointer_block_height: 0.into(),
+            ..Randomize::randomize(&mut rng)
+        };
+        let state = StateConfig {
+            coins: vec![coin],
+            messages: vec![message.clone()],
+            ..Default::default()
+        };
+        let service_config = Config::local_node_with_state_config(state);
+
+        let db = CombinedDatabase::default();
+        let task = Task::new(db, service_config).unwrap();
+        let init_result = task.into_task(&Default::default(), ()).await;
+
+        let error = format!("{:?}", init_result.err().expect("Expected an error"));
+        assert!(error.contains(&message.nonce.to_string()));
+    }
+
     #[tokio::test]
     async fn contract_tx_pointer_cant_exceed_genesis_height() {
         let mut rng = StdRng::seed_from_u64(10);
@@ -598,6 +877,207 @@ mod tests {
         assert!(init_result.is_err())
     }
 
+    #[tokio::test]
+    async fn contract_code_cant_exceed_max_size() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let chain_config = ChainConfig::local_testnet();
+        let contract_max_size = chain_config
+            .consensus_parameters
+            .contract_params()
+            .contract_max_size();
+        let code_size = usize::try_from(contract_max_size)
+            .unwrap()
+            .saturating_add(1);
+        let contract = ContractConfig {
+            code: vec![0; code_size],
+            ..given_contract_config(&mut rng)
+        };
+        let contract_id = contract.contract_id;
+        let state = StateConfig {
+            contracts: vec![contract],
+            ..Default::default()
+        };
+        let service_config = Config::local_node_with_configs(chain_config, state);
+
+        let db = CombinedDatabase::default();
+        let task = Task::new(db, service_config).unwrap();
+        let init_result = task.into_task(&Default::default(), ()).await;
+
+        let error = format!("{:?}", init_result.err().expect("Expected an error"));
+        assert!(error.contains(&contract_id.to_string()));
+    }
+
+    #[tokio::test]
+    async fn coin_cant_reuse_message_nonce_with_cross_checks() {
+        let mut rng = StdRng::seed_from_u64(10);
+
+        let message = MessageConfig {
+            da_height: DaBlockHeight(0),
+            ..MessageConfig::randomize(&mut rng)
+        };
+        let coin = CoinConfig {
+            tx_id: Bytes32::new(*message.nonce),
+            output_index: 0,
+            amount: 10,
+            ..Default::default()
+        };
+        let state = StateConfig {
+            coins: vec![coin],
+            messages: vec![message.clone()],
+            ..Default::default()
+        };
+        let mut service_config = Config::local_node_with_state_config(state);
+        service_config.genesis_cross_checks = true;
+
+        let db = CombinedDatabase::default();
+        let task = Task::new(db, service_config).unwrap();
+        let init_result = task.into_task(&Default::default(), ()).await;
+
+        let error = format!("{:?}", init_result.err().expect("Expected an error"));
+        assert!(error.contains(&message.nonce.to_string()));
+    }
+
+    #[tokio::test]
+    async fn validation_reports_all_invalid_groups_without_writing() {
+        let mut rng = StdRng::seed_from_u64(10);
+
+        let coin = CoinConfig {
+            amount: 0,
+            ..Default::default()
+        };
+        let message = MessageConfig {
+            da_height: DaBlockHeight(11),
+            ..MessageConfig::randomize(&mut rng)
+        };
+        let state = StateConfig {
+            coins: vec![coin],
+            messages: vec![message],
+            last_block: Some(LastBlockConfig {
+                da_block_height: DaBlockHeight(10),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let service_config = Config::local_node_with_state_config(state);
+        let db = CombinedDatabase::default();
+
+        let report =
+            validate_genesis_snapshot(StateWatcher::default(), &service_config, &db)
+                .await
+                .unwrap();
+
+        assert!(!report.is_valid());
+        let migrations: Vec<_> = report
+            .errors
+            .iter()
+            .map(|error| error.migration.as_str())
+            .collect();
+        assert_eq!(migrations, vec!["Coins -> Coins", "Messages -> Messages"]);
+        assert!(db.on_chain().latest_height().unwrap().is_none());
+        assert_eq!(db.on_chain().iter_all::<Coins>(None).count(), 0);
+    }
+
+    #[tokio::test]
+    async fn contracts_scope_imports_only_contract_tables() {
+        let mut rng = StdRng::seed_from_u64(10);
+
+        let contract = given_contract_config(&mut rng);
+        let contract_id = contract.contract_id;
+        let coin = CoinConfig {
+            amount: 10,
+            ..Default::default()
+        };
+        let state = StateConfig {
+            coins: vec![coin],
+            contracts: vec![contract],
+            ..Default::default()
+        };
+        let mut service_config = Config::local_node_with_state_config(state);
+        service_config.genesis_import_scope = ImportScope::contracts();
+
+        let db = CombinedDatabase::default();
+        FuelService::from_combined_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        assert!(db
+            .on_chain()
+            .storage::<ContractsRawCode>()
+            .contains_key(&contract_id)
+            .unwrap());
+        assert_eq!(db.on_chain().iter_all::<Coins>(None).count(), 0);
+    }
+
+    #[tokio::test]
+    async fn owner_indexes_are_built_unless_skipped() {
+        let owner = Address::from([1; 32]);
+        let coin = CoinConfig {
+            owner,
+            amount: 10,
+            ..Default::default()
+        };
+        let state = StateConfig {
+            coins: vec![coin],
+            ..Default::default()
+        };
+        let import = |scope: ImportScope| {
+            let mut service_config = Config::local_node_with_state_config(state.clone());
+            service_config.genesis_import_scope = scope;
+            async move {
+                let db = CombinedDatabase::default();
+                FuelService::from_combined_database(db.clone(), service_config)
+                    .await
+                    .unwrap();
+                db
+            }
+        };
+
+        let indexed = import(ImportScope::all()).await;
+        let not_indexed = import(ImportScope::all().without_owner_indexes()).await;
+
+        assert_eq!(get_coins(&indexed, &owner).len(), 1);
+        assert_eq!(not_indexed.on_chain().iter_all::<Coins>(None).count(), 1);
+        assert!(get_coins(&not_indexed, &owner).is_empty());
+    }
+
+    #[tokio::test]
+    async fn inconsistent_import_scope_is_rejected() {
+        let mut service_config =
+            Config::local_node_with_state_config(StateConfig::default());
+        service_config.genesis_import_scope =
+            ImportScope::empty().with_table::<ContractsState>();
+
+        let db = CombinedDatabase::default();
+        let task = Task::new(db, service_config).unwrap();
+        let init_result = task.into_task(&Default::default(), ()).await;
+
+        let error = format!("{:?}", init_result.err().expect("Expected an error"));
+        assert!(error.contains("`ContractsState` requires `ContractsLatestUtxo`"));
+    }
+
+    #[test]
+    fn contracts_latest_utxos_without_code_are_reported() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let contract = given_contract_config(&mut rng);
+        let dangling_contract_id = ContractId::from([1; 32]);
+        let mut db = Database::default();
+        db.storage_as_mut::<ContractsRawCode>()
+            .insert(&contract.contract_id, contract.code.as_slice())
+            .unwrap();
+        for contract_id in [contract.contract_id, dangling_contract_id] {
+            db.storage_as_mut::<ContractsLatestUtxo>()
+                .insert(&contract_id, &ContractUtxoInfo::default())
+                .unwrap();
+        }
+
+        let error = ensure_contracts_latest_utxos_have_code(&db)
+            .expect_err("Expected an error")
+            .to_string();
+
+        assert!(error.contains(&format!("{dangling_contract_id:?}")));
+        assert!(!error.contains(&format!("{:?}", contract.contract_id)));
+    }
+
     fn get_coins(db: &CombinedDatabase, owner: &Address) -> Vec<Coin> {
         db.off_chain()
             .owned_coins_ids(owner, None, None)
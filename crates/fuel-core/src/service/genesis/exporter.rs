@@ -1,7 +1,10 @@
 use crate::{
     combined_database::CombinedDatabase,
     database::{
-        database_description::DatabaseDescription,
+        database_description::{
+            on_chain::OnChain,
+            DatabaseDescription,
+        },
         Database,
     },
     fuel_core_graphql_api::storage::transactions::{
@@ -26,7 +29,11 @@ use fuel_core_chain_config::{
 use fuel_core_services::State;
 use fuel_core_storage::{
     blueprint::BlueprintInspect,
-    iter::IterDirection,
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+    },
     structured_storage::TableWithBlueprint,
     tables::{
         Coins,
@@ -39,15 +46,83 @@ use fuel_core_storage::{
         SealedBlockConsensus,
         Transactions,
     },
+    transactional::AtomicView,
+    Result as StorageResult,
+};
+use fuel_core_types::fuel_types::{
+    BlockHeight,
+    ContractId,
 };
-use fuel_core_types::fuel_types::ContractId;
 use itertools::Itertools;
 use tokio::sync::watch;
 
 use super::task_manager::TaskManager;
 
+/// Streams the entries of the table out of the on-chain database in groups,
+/// the counterpart of the `ImportTable` of the genesis importer.
+pub trait ExportTable: TableWithBlueprint + Sized {
+    /// Returns the entries of the table in the `view` with the `prefix`,
+    /// in groups of at most `group_size` entries.
+    fn export_groups(
+        view: &Database<OnChain>,
+        prefix: Option<Vec<u8>>,
+        group_size: usize,
+    ) -> BoxedIter<'_, StorageResult<Vec<TableEntry<Self>>>>;
+}
+
+fn export_groups<T>(
+    view: &Database<OnChain>,
+    prefix: Option<Vec<u8>>,
+    group_size: usize,
+) -> BoxedIter<'_, StorageResult<Vec<TableEntry<T>>>>
+where
+    T: TableWithBlueprint<Column = <OnChain as DatabaseDescription>::Column> + 'static,
+    T::Blueprint: BlueprintInspect<T, Database<OnChain>>,
+{
+    view.entries::<T>(prefix, IterDirection::Forward)
+        .batching(move |entries| {
+            match entries.take(group_size).collect::<StorageResult<Vec<_>>>() {
+                Ok(group) if group.is_empty() => None,
+                group => Some(group),
+            }
+        })
+        .into_boxed()
+}
+
+macro_rules! impl_export_table {
+    ($($table: ty),*) => {
+        $(
+            impl ExportTable for $table {
+                fn export_groups(
+                    view: &Database<OnChain>,
+                    prefix: Option<Vec<u8>>,
+                    group_size: usize,
+                ) -> BoxedIter<'_, StorageResult<Vec<TableEntry<Self>>>> {
+                    export_groups(view, prefix, group_size)
+                }
+            }
+        )*
+    };
+}
+
+impl_export_table!(
+    Coins,
+    Messages,
+    ContractsRawCode,
+    ContractsLatestUtxo,
+    ContractsState,
+    ContractsAssets
+);
+
 pub struct Exporter<Fun> {
     db: CombinedDatabase,
+    /// The on-chain database the tables of the snapshot are read from.
+    ///
+    /// The `latest_view` doesn't take a snapshot of the database yet
+    /// (https://github.com/FuelLabs/fuel-core/issues/1581), so the tables are read
+    /// from the live database. The export relies on no block being imported while
+    /// it runs, which the `finalize` verifies.
+    on_chain_view: Database<OnChain>,
     prev_chain_config: ChainConfig,
     writer: Fun,
     group_size: usize,
@@ -67,8 +142,12 @@ where
         // TODO: Support graceful shutdown during the exporting of the snapshot.
         //  https://github.com/FuelLabs/fuel-core/issues/1828
         let (_, receiver) = watch::channel(State::Started);
+        // TODO: Use the snapshot of the database when the `latest_view` supports it.
+        //  https://github.com/FuelLabs/fuel-core/issues/1581
+        let on_chain_view = db.on_chain().latest_view();
         Self {
             db,
+            on_chain_view,
             prev_chain_config,
             writer,
             group_size,
@@ -77,20 +156,31 @@ where
     }
 
     pub async fn write_full_snapshot(mut self) -> Result<(), anyhow::Error> {
-        macro_rules! export {
-            ($db: expr, $($table: ty),*) => {
-                $(self.spawn_task::<$table, _>(None, $db)?;)*
+        let height = self.start_height()?;
+
+        macro_rules! export_state {
+            ($($table: ty),*) => {
+                $(self.spawn_export::<$table>(None)?;)*
             };
         }
 
-        export!(
-            |ctx: &Self| ctx.db.on_chain(),
+        export_state!(
             Coins,
             Messages,
             ContractsRawCode,
             ContractsLatestUtxo,
             ContractsState,
-            ContractsAssets,
+            ContractsAssets
+        );
+
+        macro_rules! export {
+            ($db: expr, $($table: ty),*) => {
+                $(self.spawn_task::<$table, _>(None, $db)?;)*
+            };
+        }
+
+        export!(
+            |ctx: &Self| &ctx.on_chain_view,
             Transactions,
             FuelBlocks,
             SealedBlockConsensus
@@ -105,7 +195,7 @@ where
             OldTransactions
         );
 
-        self.finalize().await?;
+        self.finalize(height).await?;
 
         Ok(())
     }
@@ -114,9 +204,11 @@ where
         mut self,
         contract_id: ContractId,
     ) -> Result<(), anyhow::Error> {
+        let height = self.start_height()?;
+
         macro_rules! export {
             ($($table: ty),*) => {
-                $(self.spawn_task::<$table, _>(Some(contract_id.as_ref()), |ctx: &Self| ctx.db.on_chain())?;)*
+                $(self.spawn_export::<$table>(Some(contract_id.as_ref()))?;)*
             };
         }
         export!(
@@ -126,19 +218,37 @@ where
             ContractsRawCode
         );
 
-        self.finalize().await?;
+        self.finalize(height).await?;
 
         Ok(())
     }
 
-    async fn finalize(self) -> anyhow::Result<SnapshotMetadata> {
+    /// Returns the height of the on-chain database when the export starts.
+    fn start_height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        Ok(self.on_chain_view.latest_height()?)
+    }
+
+    /// Merges the fragments of all tables into the snapshot of the block at the
+    /// `height` the export started at. Fails if a block was imported during the
+    /// export, because the tables aren't read from a snapshot and could then
+    /// reflect different heights.
+    async fn finalize(
+        self,
+        height: Option<BlockHeight>,
+    ) -> anyhow::Result<SnapshotMetadata> {
         let writer = self.create_writer()?;
-        let latest_block = self.db.on_chain().latest_block()?;
+        let fragments = self.task_manager.wait().await?;
+
+        let current_height = self.on_chain_view.latest_height()?;
+        if current_height != height {
+            return Err(anyhow::anyhow!(
+                "The on-chain database advanced from {height:?} to {current_height:?} during the export"
+            ));
+        }
+        let latest_block = self.on_chain_view.latest_block()?;
 
         let writer_fragment = writer.partial_close()?;
-        self.task_manager
-            .wait()
-            .await?
+        fragments
             .into_iter()
             .try_fold(writer_fragment, |fragment, next_fragment| {
                 fragment.merge(next_fragment)
@@ -150,6 +260,30 @@ where
         (self.writer)()
     }
 
+    /// Spawns the export of the `T` table from the on-chain database.
+    fn spawn_export<T>(&mut self, prefix: Option<&[u8]>) -> anyhow::Result<()>
+    where
+        T: ExportTable + 'static + Send + Sync,
+        TableEntry<T>: serde::Serialize,
+        StateConfigBuilder: AddTable<T>,
+    {
+        let mut writer = self.create_writer()?;
+        let group_size = self.group_size;
+
+        let view = self.on_chain_view.clone();
+        let prefix = prefix.map(|p| p.to_vec());
+        self.task_manager.spawn(move |cancel| {
+            tokio_rayon::spawn(move || {
+                T::export_groups(&view, prefix, group_size)
+                    .take_while(|_| !cancel.is_cancelled())
+                    .try_for_each(|group| writer.write(group?))?;
+                writer.partial_close()
+            })
+        });
+
+        Ok(())
+    }
+
     fn spawn_task<T, DbDesc>(
         &mut self,
         prefix: Option<&[u8]>,
@@ -182,3 +316,40 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageAsMut;
+    use fuel_core_types::{
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::{
+            TxId,
+            UtxoId,
+        },
+    };
+
+    #[test]
+    fn export_groups__splits_the_table_into_groups_of_the_given_size() {
+        // Given
+        let mut db = Database::<OnChain>::default();
+        for i in 0..5u8 {
+            db.storage_as_mut::<Coins>()
+                .insert(
+                    &UtxoId::new(TxId::from([i; 32]), 0),
+                    &CompressedCoin::default(),
+                )
+                .unwrap();
+        }
+
+        // When
+        let groups = Coins::export_groups(&db, None, 2)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        let sizes: Vec<_> = groups.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+    }
+}
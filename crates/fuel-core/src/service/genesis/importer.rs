@@ -9,22 +9,49 @@ use self::{
         Target,
     },
 };
+pub use progress::{
+    GenesisMetrics,
+    GenesisProgress,
+    GenesisProgressEvent,
+    TableMetrics,
+    TableProgress,
+};
+pub use scope::ImportScope;
+pub use validators::{
+    CoinTxPointerHeight,
+    ContractCodeSize,
+    GenesisValidationContext,
+    GenesisValidator,
+    GenesisValidators,
+};
 
-use super::task_manager::TaskManager;
+use super::task_manager::{
+    CancellationToken,
+    TaskManager,
+};
 mod import_task;
 mod off_chain;
 mod on_chain;
 mod progress;
+mod scope;
+mod validators;
 use std::{
+    collections::BTreeMap,
     io::IsTerminal,
     marker::PhantomData,
+    num::NonZeroUsize,
+    sync::Arc,
 };
 
 use crate::{
     combined_database::CombinedDatabase,
-    database::database_description::{
-        off_chain::OffChain,
-        on_chain::OnChain,
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            DatabaseDescription,
+        },
+        Database,
     },
     graphql_api::storage::{
         coins::OwnedCoins,
@@ -43,13 +70,22 @@ use crate::{
 };
 use fuel_core_chain_config::{
     AsTable,
+    GroupChecksum,
     SnapshotReader,
     StateConfig,
     TableEntry,
 };
-use fuel_core_services::StateWatcher;
+use fuel_core_services::{
+    SharedMutex,
+    StateWatcher,
+};
 use fuel_core_storage::{
-    kv_store::StorageColumn,
+    blueprint::BlueprintInspect,
+    codec::Decode,
+    kv_store::{
+        StorageColumn,
+        WriteOperation,
+    },
     structured_storage::TableWithBlueprint,
     tables::{
         Coins,
@@ -62,23 +98,86 @@ use fuel_core_storage::{
         SealedBlockConsensus,
         Transactions,
     },
+    transactional::{
+        Changes,
+        StorageTransaction,
+    },
 };
 use fuel_core_types::{
     blockchain::{
         block::Block,
         primitives::DaBlockHeight,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
 };
 use tracing::Level;
 
+/// The import of one table, executed on the worker's thread.
+type Import = Box<dyn FnOnce(CancellationToken) -> anyhow::Result<()> + Send>;
+
 pub struct SnapshotImporter {
     db: CombinedDatabase,
     task_manager: TaskManager<()>,
     genesis_block: Block,
     snapshot_reader: SnapshotReader,
+    validators: GenesisValidators,
+    scope: ImportScope,
+    handler_config: HandlerConfig,
     tracing_span: tracing::Span,
     multi_progress_reporter: MultipleProgressReporter,
+    genesis_progress: GenesisProgress,
+    genesis_metrics: GenesisMetrics,
+    /// Collects the errors of all groups instead of importing them, if set.
+    validation: Option<SharedMutex<Vec<GroupValidationError>>>,
+}
+
+/// The options of the genesis import and validation, see [`SnapshotImporter::import`].
+#[derive(Debug, Default, Clone)]
+pub struct ImportOptions {
+    /// The validators run for every group before it is imported.
+    pub validators: GenesisValidators,
+    /// The tables of the snapshot to import.
+    pub scope: ImportScope,
+    /// The settings of the handlers of the on-chain tables.
+    pub handler_config: HandlerConfig,
+    /// Receives the progress of the import for each table.
+    pub progress: GenesisProgress,
+}
+
+/// The summary of the genesis import, see [`SnapshotImporter::import`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisImportReport {
+    /// The height of the imported genesis block.
+    pub block_height: BlockHeight,
+    /// The DA height of the imported genesis block.
+    pub da_block_height: DaBlockHeight,
+    /// The entries written, skipped and clamped for each migration, in the form of
+    /// `TableInSnapshot -> TableBeingWritten`.
+    pub tables: BTreeMap<String, TableMetrics>,
+}
+
+/// The error found in a group of the snapshot during the validation.
+#[derive(Debug)]
+pub struct GroupValidationError {
+    /// The name of the migration, in the form of `TableInSnapshot -> TableBeingWritten`.
+    pub migration: String,
+    pub group_index: usize,
+    pub error: anyhow::Error,
+}
+
+/// The result of the validation of the snapshot, see [`SnapshotImporter::validate`].
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    pub errors: Vec<GroupValidationError>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
 }
 
 impl SnapshotImporter {
@@ -86,33 +185,101 @@ impl SnapshotImporter {
         db: CombinedDatabase,
         genesis_block: Block,
         snapshot_reader: SnapshotReader,
+        options: ImportOptions,
         watcher: StateWatcher,
     ) -> Self {
+        let ImportOptions {
+            validators,
+            scope,
+            handler_config,
+            progress: genesis_progress,
+        } = options;
         Self {
             db,
             task_manager: TaskManager::new(watcher),
             snapshot_reader,
+            validators,
+            scope,
+            handler_config,
             genesis_block,
             tracing_span: tracing::info_span!("snapshot_importer"),
             multi_progress_reporter: Self::init_multi_progress_reporter(),
+            genesis_progress,
+            genesis_metrics: GenesisMetrics::default(),
+            validation: None,
         }
     }
 
+    /// Imports the tables of the snapshot in the scope of the `options` into the database.
+    /// The progress of the import for each table is reported into the progress
+    /// of the `options`. An inconsistent scope is rejected before anything is imported.
+    ///
+    /// Returns the report with the number of entries and bytes written
+    /// into each table. The time spent processing each table is logged
+    /// once the import completes.
     pub async fn import(
         db: CombinedDatabase,
         genesis_block: Block,
         snapshot_reader: SnapshotReader,
+        options: ImportOptions,
         watcher: StateWatcher,
-    ) -> anyhow::Result<()> {
-        Self::new(db, genesis_block, snapshot_reader, watcher)
-            .run_workers()
-            .await
+    ) -> anyhow::Result<GenesisImportReport> {
+        options.scope.validate()?;
+        let header = genesis_block.header();
+        let block_height = *header.height();
+        let da_block_height = header.da_height;
+        let importer = Self::new(db, genesis_block, snapshot_reader, options, watcher);
+        let genesis_metrics = importer.genesis_metrics.clone();
+        importer.run_workers().await?;
+
+        tracing::info!("Genesis import timings:\n{genesis_metrics}");
+        Ok(GenesisImportReport {
+            block_height,
+            da_block_height,
+            tables: genesis_metrics.tables(),
+        })
     }
 
+    /// Processes the tables of the snapshot in the scope of the `options` the same way
+    /// as [`Self::import`] does, but doesn't commit anything into the database.
+    /// The errors of all invalid groups are collected into the report instead
+    /// of aborting the validation on the first one.
+    pub async fn validate(
+        db: CombinedDatabase,
+        genesis_block: Block,
+        snapshot_reader: SnapshotReader,
+        options: ImportOptions,
+        watcher: StateWatcher,
+    ) -> anyhow::Result<ValidationReport> {
+        options.scope.validate()?;
+        let validation = SharedMutex::new(vec![]);
+        let mut importer =
+            Self::new(db, genesis_block, snapshot_reader, options, watcher);
+        importer.validation = Some(validation.clone());
+        importer.run_workers().await?;
+
+        let mut errors = validation.apply(std::mem::take);
+        errors.sort_by(|a, b| {
+            (&a.migration, a.group_index).cmp(&(&b.migration, b.group_index))
+        });
+        Ok(ValidationReport { errors })
+    }
+
+    /// Every table is imported by its own worker, so independent tables are imported
+    /// concurrently. A worker commits each group in a separate transaction, so it only
+    /// keeps one group in memory at a time.
+    ///
+    /// Workers that write into the same column run one after another inside
+    /// a single task. It keeps the final state independent of the interleaving.
     async fn run_workers(mut self) -> anyhow::Result<()> {
         tracing::info!("Running imports");
-        self.spawn_worker_on_chain::<Coins>()?;
-        self.spawn_worker_on_chain::<Messages>()?;
+        // The messages are imported after the coins, so the nonce of each message
+        // is checked against every coin of the snapshot.
+        let imports = vec![
+            self.import_on_chain::<Coins>()?,
+            self.import_on_chain::<Messages>()?,
+        ];
+        self.spawn_in_sequence(imports);
         self.spawn_worker_on_chain::<ContractsRawCode>()?;
         self.spawn_worker_on_chain::<ContractsLatestUtxo>()?;
         self.spawn_worker_on_chain::<ContractsState>()?;
@@ -122,14 +289,27 @@ impl SnapshotImporter {
         self.spawn_worker_off_chain::<OwnedTransactions, OwnedTransactions>()?;
         self.spawn_worker_off_chain::<Messages, OwnedMessageIds>()?;
         self.spawn_worker_off_chain::<Coins, OwnedCoins>()?;
-        self.spawn_worker_off_chain::<FuelBlocks, OldFuelBlocks>()?;
-        self.spawn_worker_off_chain::<Transactions, OldTransactions>()?;
-        self.spawn_worker_off_chain::<SealedBlockConsensus, OldFuelBlockConsensus>()?;
-        self.spawn_worker_off_chain::<Transactions, ContractsInfo>()?;
-        self.spawn_worker_off_chain::<OldTransactions, ContractsInfo>()?;
-        self.spawn_worker_off_chain::<OldFuelBlocks, OldFuelBlocks>()?;
-        self.spawn_worker_off_chain::<OldFuelBlockConsensus, OldFuelBlockConsensus>()?;
-        self.spawn_worker_off_chain::<OldTransactions, OldTransactions>()?;
+
+        let imports = vec![
+            self.import_off_chain::<FuelBlocks, OldFuelBlocks>()?,
+            self.import_off_chain::<OldFuelBlocks, OldFuelBlocks>()?,
+        ];
+        self.spawn_in_sequence(imports);
+        let imports = vec![
+            self.import_off_chain::<Transactions, OldTransactions>()?,
+            self.import_off_chain::<OldTransactions, OldTransactions>()?,
+        ];
+        self.spawn_in_sequence(imports);
+        let imports = vec![
+            self.import_off_chain::<SealedBlockConsensus, OldFuelBlockConsensus>()?,
+            self.import_off_chain::<OldFuelBlockConsensus, OldFuelBlockConsensus>()?,
+        ];
+        self.spawn_in_sequence(imports);
+        let imports = vec![
+            self.import_off_chain::<Transactions, ContractsInfo>()?,
+            self.import_off_chain::<OldTransactions, ContractsInfo>()?,
+        ];
+        self.spawn_in_sequence(imports);
 
         self.task_manager.wait().await?;
 
@@ -144,6 +324,54 @@ impl SnapshotImporter {
         Handler<TableBeingWritten, TableBeingWritten>:
             ImportTable<TableInSnapshot = TableBeingWritten, DbDesc = OnChain>,
     {
+        let import = self.import_on_chain::<TableBeingWritten>()?;
+        self.spawn_in_sequence(vec![import]);
+        Ok(())
+    }
+
+    pub fn spawn_worker_off_chain<TableInSnapshot, TableBeingWritten>(
+        &mut self,
+    ) -> anyhow::Result<()>
+    where
+        TableInSnapshot: TableWithBlueprint + Send + 'static,
+        TableEntry<TableInSnapshot>: serde::de::DeserializeOwned + Send,
+        StateConfig: AsTable<TableInSnapshot>,
+        Handler<TableBeingWritten, TableInSnapshot>:
+            ImportTable<TableInSnapshot = TableInSnapshot, DbDesc = OffChain>,
+        TableBeingWritten: TableWithBlueprint + Send + 'static,
+    {
+        let import = self.import_off_chain::<TableInSnapshot, TableBeingWritten>()?;
+        self.spawn_in_sequence(vec![import]);
+        Ok(())
+    }
+
+    /// Spawns a worker that runs the `imports` one after another.
+    /// The imports of the tables out of the scope are `None` and skipped.
+    fn spawn_in_sequence(&mut self, imports: Vec<Option<Import>>) {
+        let imports: Vec<_> = imports.into_iter().flatten().collect();
+        if imports.is_empty() {
+            return
+        }
+        self.task_manager.spawn(move |token| {
+            tokio_rayon::spawn(move || {
+                imports
+                    .into_iter()
+                    .try_for_each(|import| import(token.clone()))
+            })
+        });
+    }
+
+    fn import_on_chain<TableBeingWritten>(&self) -> anyhow::Result<Option<Import>>
+    where
+        TableBeingWritten: TableWithBlueprint + 'static + Send,
+        TableEntry<TableBeingWritten>: serde::de::DeserializeOwned + Send,
+        StateConfig: AsTable<TableBeingWritten>,
+        Handler<TableBeingWritten, TableBeingWritten>:
+            ImportTable<TableInSnapshot = TableBeingWritten, DbDesc = OnChain>,
+    {
+        if !self.scope.contains::<TableBeingWritten>() {
+            return Ok(None)
+        }
         let groups = self.snapshot_reader.read::<TableBeingWritten>()?;
         let num_groups = groups.len();
 
@@ -154,24 +382,38 @@ impl SnapshotImporter {
         let progress_name = migration_name::<TableBeingWritten, TableBeingWritten>();
 
         let progress_reporter = self.progress_reporter(progress_name, num_groups);
-
-        self.task_manager.spawn(move |token| {
-            let task = ImportTask::new(
-                token,
-                Handler::new(block_height, da_block_height),
-                groups,
-                db,
-                progress_reporter,
+        let contract_max_size = self
+            .snapshot_reader
+            .chain_config()
+            .consensus_parameters
+            .contract_params()
+            .contract_max_size();
+        let handler = Handler::new(block_height, da_block_height)
+            .with_config(self.handler_config)
+            .with_contract_max_size(contract_max_size)
+            .with_validators(self.validators.clone())
+            .with_group_checksums(
+                self.snapshot_reader
+                    .group_checksums(TableBeingWritten::column().name()),
             );
-            tokio_rayon::spawn(move || task.run())
-        });
 
-        Ok(())
+        let validation = self.validation.clone();
+        Ok(Some(Box::new(move |token| {
+            let task = ImportTask::new(token, handler, groups, db, progress_reporter);
+            match validation {
+                Some(validation) => {
+                    let errors = task.validate()?;
+                    validation.apply(|all| all.extend(errors));
+                    Ok(())
+                }
+                None => task.run(),
+            }
+        })))
     }
 
-    pub fn spawn_worker_off_chain<TableInSnapshot, TableBeingWritten>(
-        &mut self,
-    ) -> anyhow::Result<()>
+    fn import_off_chain<TableInSnapshot, TableBeingWritten>(
+        &self,
+    ) -> anyhow::Result<Option<Import>>
     where
         TableInSnapshot: TableWithBlueprint + Send + 'static,
         TableEntry<TableInSnapshot>: serde::de::DeserializeOwned + Send,
@@ -180,30 +422,39 @@ impl SnapshotImporter {
             ImportTable<TableInSnapshot = TableInSnapshot, DbDesc = OffChain>,
         TableBeingWritten: TableWithBlueprint + Send + 'static,
     {
+        if !self.scope.contains::<TableInSnapshot>()
+            || !self.scope.builds_index::<TableBeingWritten>()
+        {
+            return Ok(None)
+        }
         let groups = self.snapshot_reader.read::<TableInSnapshot>()?;
         let num_groups = groups.len();
         let block_height = *self.genesis_block.header().height();
         let da_block_height = self.genesis_block.header().da_height;
 
         let db = self.db.off_chain().clone();
+        let handler = Handler::new(block_height, da_block_height).with_group_checksums(
+            self.snapshot_reader
+                .group_checksums(TableInSnapshot::column().name()),
+        );
 
         let progress_reporter = self.progress_reporter(
             migration_name::<TableInSnapshot, TableBeingWritten>(),
             num_groups,
         );
 
-        self.task_manager.spawn(move |token| {
-            let task = ImportTask::new(
-                token,
-                Handler::new(block_height, da_block_height),
-                groups,
-                db,
-                progress_reporter,
-            );
-            tokio_rayon::spawn(move || task.run())
-        });
-
-        Ok(())
+        let validation = self.validation.clone();
+        Ok(Some(Box::new(move |token| {
+            let task = ImportTask::new(token, handler, groups, db, progress_reporter);
+            match validation {
+                Some(validation) => {
+                    let errors = task.validate()?;
+                    validation.apply(|all| all.extend(errors));
+                    Ok(())
+                }
+                None => task.run(),
+            }
+        })))
     }
 
     fn init_multi_progress_reporter() -> MultipleProgressReporter {
@@ -220,7 +471,7 @@ impl SnapshotImporter {
 
     fn progress_reporter(&self, name: String, num_groups: usize) -> ProgressReporter {
         let target = if Self::should_display_bars() {
-            Target::Cli(name)
+            Target::Cli(name.clone())
         } else {
             let span = tracing::span!(
                 parent: &self.tracing_span,
@@ -231,15 +482,70 @@ impl SnapshotImporter {
             Target::Logs(span)
         };
 
-        let reporter = ProgressReporter::new(target, num_groups);
+        let reporter = ProgressReporter::new(target, num_groups)
+            .with_genesis_metrics(name.clone(), self.genesis_metrics.clone())
+            .with_genesis_progress(name, self.genesis_progress.clone());
         self.multi_progress_reporter.register(reporter)
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Defines how the coins with the `tx_pointer` above the genesis block are imported.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CoinTxPointerPolicy {
+    /// The coin is rejected, and the import fails.
+    #[default]
+    Strict,
+    /// The height of the coin's `tx_pointer` is lowered to the genesis block height.
+    ClampToGenesis,
+}
+
+/// Defines the commit boundaries of the contract state imports.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ContractStateCommits {
+    /// The whole group is committed at once.
+    #[default]
+    PerGroup,
+    /// The state of each contract in the group is committed separately. A failure
+    /// doesn't roll back the contracts of the group that are already committed,
+    /// so the group is no longer imported atomically.
+    PerContract,
+}
+
+/// The settings of the handlers of the on-chain tables built by the importer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HandlerConfig {
+    /// The handling of the coins with the `tx_pointer` above the genesis block.
+    pub coin_tx_pointer_policy: CoinTxPointerPolicy,
+    /// The commit boundaries of the contract state imports.
+    pub contract_state_commits: ContractStateCommits,
+    /// The maximum number of the contract state entries written per sub-batch,
+    /// see [`Handler::chunk_size`].
+    pub chunk_size: Option<NonZeroUsize>,
+}
+
+#[derive(Debug, Clone)]
 pub struct Handler<TableBeingWritten, TableInSnapshot> {
-    pub block_height: BlockHeight,
-    pub da_block_height: DaBlockHeight,
+    block_height: BlockHeight,
+    da_block_height: DaBlockHeight,
+    /// The maximum number of the contract state entries written per sub-batch.
+    /// The sub-batches are flushed into the transaction of the group, so the group
+    /// is still committed or discarded as a whole. The whole group is written
+    /// at once if it is `None`.
+    chunk_size: Option<NonZeroUsize>,
+    /// The maximum size of the contract bytecode allowed by the consensus parameters.
+    contract_max_size: u64,
+    /// The handling of the coins with the `tx_pointer` above the genesis block.
+    coin_tx_pointer_policy: CoinTxPointerPolicy,
+    /// The commit boundaries of the contract state imports.
+    contract_state_commits: ContractStateCommits,
+    /// The validators run for every group before it is imported.
+    validators: GenesisValidators,
+    /// The expected checksums of the groups, if the verification is enabled.
+    group_checksums: Option<Arc<[Bytes32]>>,
+    /// The checksum of the entries written for the current group.
+    written_checksum: GroupChecksum,
+    /// The number of entries clamped to fit the genesis block since they were last reported.
+    clamped_entries: u64,
     _table_being_written: PhantomData<TableBeingWritten>,
     _table_in_snapshot: PhantomData<TableInSnapshot>,
 }
@@ -249,10 +555,213 @@ impl<A, B> Handler<A, B> {
         Self {
             block_height,
             da_block_height,
+            chunk_size: None,
+            contract_max_size: u64::MAX,
+            coin_tx_pointer_policy: CoinTxPointerPolicy::default(),
+            contract_state_commits: ContractStateCommits::default(),
+            validators: GenesisValidators::default(),
+            group_checksums: None,
+            written_checksum: GroupChecksum::default(),
+            clamped_entries: 0,
             _table_being_written: PhantomData,
             _table_in_snapshot: PhantomData,
         }
     }
+
+    pub fn with_config(self, config: HandlerConfig) -> Self {
+        let HandlerConfig {
+            coin_tx_pointer_policy,
+            contract_state_commits,
+            chunk_size,
+        } = config;
+        Self {
+            coin_tx_pointer_policy,
+            contract_state_commits,
+            chunk_size,
+            ..self
+        }
+    }
+
+    pub fn with_contract_max_size(self, contract_max_size: u64) -> Self {
+        Self {
+            contract_max_size,
+            ..self
+        }
+    }
+
+    pub fn with_validators(self, validators: GenesisValidators) -> Self {
+        Self { validators, ..self }
+    }
+
+    pub fn with_group_checksums(self, group_checksums: Option<&[Bytes32]>) -> Self {
+        Self {
+            group_checksums: group_checksums.map(Arc::from),
+            ..self
+        }
+    }
+}
+
+impl<A, TableInSnapshot> Handler<A, TableInSnapshot>
+where
+    TableInSnapshot: TableWithBlueprint + 'static,
+{
+    /// Runs the registered validators of the table on the group.
+    fn verify_group(&self, group: &[TableEntry<TableInSnapshot>]) -> anyhow::Result<()> {
+        let context = GenesisValidationContext {
+            block_height: self.block_height,
+            da_block_height: self.da_block_height,
+            contract_max_size: self.contract_max_size,
+            coin_tx_pointer_policy: self.coin_tx_pointer_policy,
+        };
+        self.validators
+            .of::<TableInSnapshot>()
+            .iter()
+            .try_for_each(|validator| validator.validate_group(&context, group))
+    }
+}
+
+type KeyCodec<T, DbDesc> = <<T as TableWithBlueprint>::Blueprint as BlueprintInspect<
+    T,
+    Database<DbDesc>,
+>>::KeyCodec;
+type ValueCodec<T, DbDesc> = <<T as TableWithBlueprint>::Blueprint as BlueprintInspect<
+    T,
+    Database<DbDesc>,
+>>::ValueCodec;
+
+/// The import of the entries of a snapshot table into the same table.
+///
+/// [`ImportTable`] is implemented for the handlers of these tables, so all of them
+/// verify the groups with the registered validators and compare what they wrote
+/// with the checksums of the snapshot.
+pub trait ImportSnapshotTable {
+    type Table: TableWithBlueprint;
+    type DbDesc: DatabaseDescription;
+
+    /// See [`ImportTable::process`].
+    fn import_group(
+        &mut self,
+        group: Vec<TableEntry<Self::Table>>,
+        tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
+    ) -> anyhow::Result<()>;
+
+    /// See [`ImportTable::split_into_commits`].
+    fn split_group(
+        &self,
+        group: Vec<TableEntry<Self::Table>>,
+    ) -> Vec<Vec<TableEntry<Self::Table>>> {
+        vec![group]
+    }
+}
+
+impl<T, DbDesc> ImportTable for Handler<T, T>
+where
+    Self: ImportSnapshotTable<Table = T, DbDesc = DbDesc>,
+    T: TableWithBlueprint + 'static,
+    T::Blueprint: BlueprintInspect<T, Database<DbDesc>>,
+    TableEntry<T>: serde::Serialize,
+    DbDesc: DatabaseDescription<Column = T::Column>,
+{
+    type TableInSnapshot = T;
+    type TableBeingWritten = T;
+    type DbDesc = DbDesc;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<T>>,
+        tx: &mut StorageTransaction<&mut Database<DbDesc>>,
+    ) -> anyhow::Result<()> {
+        self.import_group(group, tx)
+    }
+
+    fn verify(&self, _group_index: usize, group: &[TableEntry<T>]) -> anyhow::Result<()> {
+        self.verify_group(group)
+    }
+
+    fn split_into_commits(&self, group: Vec<TableEntry<T>>) -> Vec<Vec<TableEntry<T>>> {
+        self.split_group(group)
+    }
+
+    fn record_written(&mut self, changes: &Changes) -> anyhow::Result<()> {
+        self.record_written_entries::<DbDesc>(changes)
+    }
+
+    fn verify_written(&mut self, group_index: usize) -> anyhow::Result<()> {
+        // The clamped coins are written with another `tx_pointer` than
+        // in the snapshot, so the group can't match its checksum.
+        if self.clamped_entries > 0 {
+            self.written_checksum = Default::default();
+            return Ok(())
+        }
+        self.verify_group_checksum(group_index)
+    }
+
+    fn take_clamped_entries(&mut self) -> u64 {
+        core::mem::take(&mut self.clamped_entries)
+    }
+}
+
+/// The handlers writing the entries of the snapshot table as they are verify
+/// the checksums of the groups against what they wrote. The handlers deriving
+/// other tables from the snapshot don't write the snapshot table, so they don't.
+impl<T> Handler<T, T>
+where
+    T: TableWithBlueprint + 'static,
+    TableEntry<T>: serde::Serialize,
+{
+    /// Adds the entries of the table written by the `changes` to the checksum of
+    /// the group. The entries are decoded from the written bytes, so the checksum
+    /// covers what is committed into the database.
+    fn record_written_entries<DbDesc>(&mut self, changes: &Changes) -> anyhow::Result<()>
+    where
+        DbDesc: DatabaseDescription<Column = T::Column>,
+        T::Blueprint: BlueprintInspect<T, Database<DbDesc>>,
+    {
+        if self.group_checksums.is_none() {
+            return Ok(())
+        }
+        let Some(written) = changes.get(&T::column().id()) else {
+            return Ok(())
+        };
+        for (key, operation) in written {
+            let WriteOperation::Insert(value) = operation else {
+                anyhow::bail!(
+                    "The import of the table `{}` removed an entry",
+                    T::column().name()
+                );
+            };
+            let entry = TableEntry::<T> {
+                key: KeyCodec::<T, DbDesc>::decode(key)?,
+                value: ValueCodec::<T, DbDesc>::decode_from_value(value.clone())?,
+            };
+            self.written_checksum.add(&entry)?;
+        }
+        Ok(())
+    }
+
+    /// Compares the checksum of the entries written for the group with
+    /// the expected one from the snapshot.
+    fn verify_group_checksum(&mut self, group_index: usize) -> anyhow::Result<()> {
+        let written = core::mem::take(&mut self.written_checksum);
+        let Some(group_checksums) = &self.group_checksums else {
+            return Ok(())
+        };
+        let table = T::column().name();
+        let expected = group_checksums.get(group_index).ok_or_else(|| {
+            anyhow::anyhow!(
+                "No checksum for the group {group_index} of the table `{table}`"
+            )
+        })?;
+
+        let actual = written.finalize();
+        if actual != *expected {
+            anyhow::bail!(
+                "Checksum mismatch for the group {group_index} of the table `{table}`: \
+                expected {expected}, got {actual}"
+            );
+        }
+        Ok(())
+    }
 }
 
 fn migration_name<TableInSnapshot, TableBeingWritten>() -> String
@@ -1,8 +1,10 @@
 use anyhow::bail;
 use fuel_core_chain_config::TableEntry;
 use fuel_core_storage::{
+    kv_store::WriteOperation,
     structured_storage::TableWithBlueprint,
     transactional::{
+        Changes,
         Modifiable,
         StorageTransaction,
         WriteTransaction,
@@ -11,6 +13,7 @@ use fuel_core_storage::{
     StorageInspect,
     StorageMutate,
 };
+use std::time::Instant;
 
 use crate::{
     database::{
@@ -22,7 +25,10 @@ use crate::{
         Database,
     },
     service::genesis::{
-        importer::migration_name,
+        importer::{
+            migration_name,
+            GroupValidationError,
+        },
         task_manager::CancellationToken,
     },
 };
@@ -51,6 +57,60 @@ pub trait ImportTable {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
     ) -> anyhow::Result<()>;
+
+    /// Verifies the integrity of the group before it is processed.
+    /// Does nothing by default.
+    fn verify(
+        &self,
+        _group_index: usize,
+        _group: &[TableEntry<Self::TableInSnapshot>],
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Splits the group into the parts that are processed and committed one after
+    /// another. The parts committed before a failing part stay in the database.
+    /// By default, the group is a single part, so it is committed atomically
+    /// after its checksum is verified.
+    fn split_into_commits(
+        &self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+    ) -> Vec<Vec<TableEntry<Self::TableInSnapshot>>> {
+        vec![group]
+    }
+
+    /// Records the `changes` written by a processed part of the group before
+    /// they are committed. Does nothing by default.
+    fn record_written(&mut self, _changes: &Changes) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Verifies the integrity of the changes recorded for the group by
+    /// [`Self::record_written`] before the checkpoint of the group is committed.
+    /// Does nothing by default.
+    fn verify_written(&mut self, _group_index: usize) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Returns the number of entries adjusted to fit the genesis block since
+    /// the previous call. The handler doesn't adjust entries by default.
+    fn take_clamped_entries(&mut self) -> u64 {
+        0
+    }
+}
+
+/// Returns the total size of the keys and values inserted by the `changes`.
+fn changes_size(changes: &Changes) -> u64 {
+    changes
+        .values()
+        .flatten()
+        .map(|(key, operation)| match operation {
+            WriteOperation::Insert(value) => key.len().saturating_add(value.len()),
+            WriteOperation::Remove => 0,
+        })
+        .fold(0u64, |size, entry_size| {
+            size.saturating_add(u64::try_from(entry_size).unwrap_or(u64::MAX))
+        })
 }
 
 impl<Logic, GroupGenerator, DbDesc> ImportTask<Logic, GroupGenerator, DbDesc>
@@ -77,6 +137,7 @@ where
             }
             _ => 0,
         };
+        reporter.resume_from(skip);
 
         Self {
             handler,
@@ -106,8 +167,21 @@ where
     for<'a> StorageTransaction<&'a mut Database<DbDesc>>:
         StorageMutate<GenesisMetadata<DbDesc>, Error = fuel_core_storage::Error>,
 {
+    /// Imports the groups one by one, committing each group together with the index
+    /// of the group as the checkpoint. The groups up to the checkpoint of the previous
+    /// import are skipped without being read. If the handler splits the group with
+    /// [`ImportTable::split_into_commits`], the checkpoint is committed with the last part.
+    /// A group interrupted by an error is imported again from the first part on resume.
+    /// Empty groups are committed like any other, so they advance the checkpoint too.
     pub fn run(mut self) -> anyhow::Result<()> {
         let mut db = self.db;
+        if self.skip > 0 {
+            tracing::info!(
+                "Resuming the import of {} from the group {}",
+                migration_name::<Logic::TableInSnapshot, Logic::TableBeingWritten>(),
+                self.skip
+            );
+        }
         let mut is_cancelled = self.cancel_token.is_cancelled();
         self.groups
             .into_iter()
@@ -119,8 +193,28 @@ where
             })
             .try_for_each(|(index, group)| {
                 let group = group?;
+                let entries = group.len();
+                self.handler.verify(index, &group)?;
+                let started_at = Instant::now();
+                let mut parts = self.handler.split_into_commits(group);
+                let last_part = parts.pop().unwrap_or_default();
+                let mut bytes = 0u64;
+                for part in parts {
+                    let mut tx = db.write_transaction();
+                    self.handler.process(part, &mut tx)?;
+                    let changes = tx.into_changes();
+                    self.handler.record_written(&changes)?;
+                    bytes = bytes.saturating_add(changes_size(&changes));
+                    db.commit_changes(changes)?;
+                }
                 let mut tx = db.write_transaction();
-                self.handler.process(group, &mut tx)?;
+                self.handler.process(last_part, &mut tx)?;
+                self.reporter.record_processing_time(started_at.elapsed());
+                let changes = tx.into_changes();
+                self.handler.record_written(&changes)?;
+                self.handler.verify_written(index)?;
+                bytes = bytes.saturating_add(changes_size(&changes));
+                let mut tx = db.write_transaction().with_changes(changes);
 
                 let progress_name =
                     migration_name::<Logic::TableInSnapshot, Logic::TableBeingWritten>();
@@ -133,6 +227,11 @@ where
                 tx.commit()?;
                 self.reporter
                     .set_progress(u64::try_from(index).unwrap_or(u64::MAX));
+                self.reporter.add_entries_written(entries);
+                self.reporter.report_committed();
+                self.reporter.record_written(entries, bytes);
+                self.reporter
+                    .record_clamped(self.handler.take_clamped_entries());
                 anyhow::Result::<_>::Ok(())
             })?;
 
@@ -142,6 +241,53 @@ where
 
         Ok(())
     }
+
+    /// Processes all groups without modifying the database: every group is written
+    /// into its own transaction that is dropped afterward. Unlike [`Self::run`], it
+    /// doesn't stop on the first invalid group but returns the errors of all of them.
+    /// The progress of the previous imports is ignored, and the new one is not recorded.
+    pub fn validate(mut self) -> anyhow::Result<Vec<GroupValidationError>> {
+        let mut db = self.db;
+        let migration =
+            migration_name::<Logic::TableInSnapshot, Logic::TableBeingWritten>();
+        let mut errors = vec![];
+        for (index, group) in self.groups.into_iter().enumerate() {
+            if self.cancel_token.is_cancelled() {
+                bail!("Validation cancelled")
+            }
+
+            let result = group.and_then(|group| {
+                let entries = group.len();
+                self.handler.verify(index, &group)?;
+                let mut tx = db.write_transaction();
+                let started_at = Instant::now();
+                self.handler.process(group, &mut tx)?;
+                self.reporter.record_processing_time(started_at.elapsed());
+                // The recorded changes are verified even if recording fails,
+                // so they don't leak into the next group.
+                let recorded = self.handler.record_written(&tx.into_changes());
+                let verified = self.handler.verify_written(index);
+                recorded.and(verified)?;
+                Ok(entries)
+            });
+            match result {
+                Ok(entries) => self.reporter.add_entries_written(entries),
+                Err(error) => errors.push(GroupValidationError {
+                    migration: migration.clone(),
+                    group_index: index,
+                    error,
+                }),
+            }
+            // Taken after every group, like in `run`, so the clamped entries
+            // of one group don't affect the verification of the next ones.
+            self.reporter
+                .record_clamped(self.handler.take_clamped_entries());
+            self.reporter
+                .set_progress(u64::try_from(index).unwrap_or(u64::MAX));
+        }
+
+        Ok(errors)
+    }
 }
 
 #[cfg(test)]
@@ -151,7 +297,11 @@ mod tests {
         service::genesis::{
             importer::{
                 import_task::ImportTask,
-                progress::ProgressReporter,
+                progress::{
+                    GenesisMetrics,
+                    GenesisProgress,
+                    ProgressReporter,
+                },
             },
             task_manager::CancellationToken,
         },
@@ -340,6 +490,64 @@ mod tests {
         assert_eq!(called_with, data.as_entries(1));
     }
 
+    /// Records the indexes of the groups that were read.
+    struct ReadTrackingGroups {
+        groups: std::vec::IntoIter<anyhow::Result<Vec<TableEntry<Coins>>>>,
+        index: usize,
+        read: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl Iterator for ReadTrackingGroups {
+        type Item = anyhow::Result<Vec<TableEntry<Coins>>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let group = self.groups.next()?;
+            self.read.lock().unwrap().push(self.index);
+            self.index = self.index.saturating_add(1);
+            Some(group)
+        }
+
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            if let Some(last_skipped) = n.checked_sub(1) {
+                self.groups.nth(last_skipped)?;
+            }
+            self.index = self.index.saturating_add(n);
+            self.next()
+        }
+    }
+
+    #[test]
+    fn resumed_import_doesnt_read_the_imported_groups() {
+        // given
+        let data = TestData::new(3);
+        let mut db = CombinedDatabase::default();
+        GenesisProgressMutate::<OnChain>::update_genesis_progress(
+            db.on_chain_mut(),
+            migration_name::<Coins, Coins>().as_str(),
+            1,
+        )
+        .unwrap();
+        let read = Arc::new(Mutex::new(vec![]));
+        let groups = ReadTrackingGroups {
+            groups: data.as_ok_groups().into_iter(),
+            index: 0,
+            read: read.clone(),
+        };
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            TestHandler::new(|_, _| Ok(())),
+            groups,
+            db.on_chain().clone(),
+            ProgressReporter::default(),
+        );
+
+        // when
+        runner.run().unwrap();
+
+        // then
+        assert_eq!(*read.lock().unwrap(), vec![2]);
+    }
+
     #[test]
     fn changes_to_db_by_handler_are_behind_a_transaction() {
         // given
@@ -413,6 +621,73 @@ mod tests {
         assert!(!StorageInspect::<Coins>::contains_key(&db, &utxo_id).unwrap());
     }
 
+    /// Commits every entry of the group separately and fails on the `fail_at` entry.
+    struct PerEntryCommits {
+        fail_at: usize,
+        processed: usize,
+    }
+
+    impl ImportTable for PerEntryCommits {
+        type TableInSnapshot = Coins;
+        type TableBeingWritten = Coins;
+        type DbDesc = OnChain;
+
+        fn split_into_commits(
+            &self,
+            group: Vec<TableEntry<Self::TableInSnapshot>>,
+        ) -> Vec<Vec<TableEntry<Self::TableInSnapshot>>> {
+            group.into_iter().map(|entry| vec![entry]).collect()
+        }
+
+        fn process(
+            &mut self,
+            group: Vec<TableEntry<Self::TableInSnapshot>>,
+            tx: &mut StorageTransaction<&mut Database>,
+        ) -> anyhow::Result<()> {
+            for entry in group {
+                if self.processed == self.fail_at {
+                    bail!("Some error")
+                }
+                self.processed = self.processed.saturating_add(1);
+                tx.storage_as_mut::<Coins>()
+                    .insert(&entry.key, &entry.value)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn parts_committed_before_the_failing_part_are_kept() {
+        // given
+        let group = TestData::new(2).as_entries(0);
+        let db = Database::default();
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            PerEntryCommits {
+                fail_at: 1,
+                processed: 0,
+            },
+            vec![Ok(group.clone())],
+            db.clone(),
+            ProgressReporter::default(),
+        );
+
+        // when
+        let result = runner.run();
+
+        // then
+        assert!(result.is_err());
+        assert!(StorageInspect::<Coins>::contains_key(&db, &group[0].key).unwrap());
+        assert!(!StorageInspect::<Coins>::contains_key(&db, &group[1].key).unwrap());
+        assert_eq!(
+            GenesisProgressInspect::<OnChain>::genesis_progress(
+                &db,
+                migration_name::<Coins, Coins>().as_str(),
+            ),
+            None
+        );
+    }
+
     #[test]
     fn handler_failure_is_propagated() {
         // given
@@ -451,6 +726,61 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn validation_reports_errors_of_all_groups() {
+        // given
+        let data = TestData::new(3);
+        let mut groups = data.as_ok_groups();
+        groups[0] = Err(anyhow!("Invalid group"));
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            TestHandler::new(|_, _| bail!("Some error")),
+            groups,
+            Database::default(),
+            ProgressReporter::default(),
+        );
+
+        // when
+        let errors = runner.validate().unwrap();
+
+        // then
+        let group_indexes: Vec<_> =
+            errors.iter().map(|error| error.group_index).collect();
+        assert_eq!(group_indexes, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn validation_doesnt_modify_the_db() {
+        // given
+        let data = TestData::new(2);
+        let db = Database::default();
+        let utxo_id = UtxoId::new(Default::default(), 0);
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            TestHandler::new(|_, tx| {
+                insert_a_coin(tx, &utxo_id);
+                Ok(())
+            }),
+            data.as_ok_groups(),
+            db.clone(),
+            ProgressReporter::default(),
+        );
+
+        // when
+        let errors = runner.validate().unwrap();
+
+        // then
+        assert!(errors.is_empty());
+        assert!(!StorageInspect::<Coins>::contains_key(&db, &utxo_id).unwrap());
+        assert_eq!(
+            GenesisProgressInspect::<OnChain>::genesis_progress(
+                &db,
+                migration_name::<Coins, Coins>().as_str(),
+            ),
+            None
+        );
+    }
+
     #[test]
     fn succesfully_processed_batch_updates_the_genesis_progress() {
         // given
@@ -477,6 +807,247 @@ mod tests {
         );
     }
 
+    #[test]
+    fn genesis_progress_is_resumed_from_the_last_processed_group() {
+        // given
+        let data = TestData::new(3);
+        let mut db = CombinedDatabase::default();
+        GenesisProgressMutate::<OnChain>::update_genesis_progress(
+            db.on_chain_mut(),
+            migration_name::<Coins, Coins>().as_str(),
+            0,
+        )
+        .unwrap();
+        let progress = GenesisProgress::default();
+        let reporter = ProgressReporter::default()
+            .with_genesis_progress("coins".to_string(), progress.clone());
+
+        // when
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            TestHandler::new(|_, _| Ok(())),
+            data.as_ok_groups(),
+            db.on_chain().clone(),
+            reporter,
+        );
+
+        // then
+        assert_eq!(progress.table("coins").unwrap().groups_processed, 1);
+        runner.run().unwrap();
+        let table_progress = progress.table("coins").unwrap();
+        assert_eq!(table_progress.groups_processed, 3);
+        assert_eq!(table_progress.entries_written, 2);
+    }
+
+    /// Places an empty group before and after each group of the `data`.
+    fn interspersed_with_empty_groups(
+        data: &TestData,
+    ) -> Vec<anyhow::Result<Vec<TableEntry<Coins>>>> {
+        data.as_groups()
+            .into_iter()
+            .flat_map(|group| [vec![], group])
+            .chain([vec![]])
+            .map(Ok)
+            .collect()
+    }
+
+    #[test]
+    fn empty_groups_advance_the_checkpoint_and_progress() {
+        // given
+        let data = TestData::new(2);
+        let groups = interspersed_with_empty_groups(&data);
+        let db = Database::default();
+        let progress = GenesisProgress::default();
+        let metrics = GenesisMetrics::default();
+        let reporter = ProgressReporter::default()
+            .with_genesis_progress("coins".to_string(), progress.clone())
+            .with_genesis_metrics("coins".to_string(), metrics.clone());
+        let mut called_with = vec![];
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            TestHandler::new(|entry, _| {
+                called_with.push(entry);
+                Ok(())
+            }),
+            groups,
+            db.clone(),
+            reporter,
+        );
+
+        // when
+        runner.run().unwrap();
+
+        // then
+        assert_eq!(called_with, data.as_entries(0));
+        assert_eq!(
+            GenesisProgressInspect::<OnChain>::genesis_progress(
+                &db,
+                migration_name::<Coins, Coins>().as_str(),
+            ),
+            Some(4)
+        );
+        let table_progress = progress.table("coins").unwrap();
+        assert_eq!(table_progress.groups_processed, 5);
+        assert_eq!(table_progress.entries_written, 2);
+        let table_metrics = metrics.table("coins").unwrap();
+        assert_eq!(table_metrics.groups_processed, 5);
+        assert_eq!(table_metrics.entries_written, 2);
+    }
+
+    #[test]
+    fn import_resumes_after_the_checkpoint_of_an_empty_group() {
+        // given
+        let data = TestData::new(2);
+        let mut db = CombinedDatabase::default();
+        GenesisProgressMutate::<OnChain>::update_genesis_progress(
+            db.on_chain_mut(),
+            migration_name::<Coins, Coins>().as_str(),
+            2,
+        )
+        .unwrap();
+        let progress = GenesisProgress::default();
+        let reporter = ProgressReporter::default()
+            .with_genesis_progress("coins".to_string(), progress.clone());
+        let mut called_with = vec![];
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            TestHandler::new(|entry, _| {
+                called_with.push(entry);
+                Ok(())
+            }),
+            interspersed_with_empty_groups(&data),
+            db.on_chain().clone(),
+            reporter,
+        );
+
+        // when
+        runner.run().unwrap();
+
+        // then
+        assert_eq!(called_with, data.as_entries(1));
+        let table_progress = progress.table("coins").unwrap();
+        assert_eq!(table_progress.groups_processed, 5);
+        assert_eq!(table_progress.entries_written, 1);
+    }
+
+    #[test]
+    fn processing_time_is_recorded_per_group() {
+        // given
+        let data = TestData::new(3);
+        let metrics = GenesisMetrics::default();
+        let reporter = ProgressReporter::default()
+            .with_genesis_metrics("coins".to_string(), metrics.clone());
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            TestHandler::new(|_, _| {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+                Ok(())
+            }),
+            data.as_ok_groups(),
+            Database::default(),
+            reporter,
+        );
+
+        // when
+        runner.run().unwrap();
+
+        // then
+        let table_metrics = metrics.table("coins").unwrap();
+        assert_eq!(table_metrics.groups_processed, 3);
+        assert!(table_metrics.processing_time >= std::time::Duration::from_millis(3));
+    }
+
+    #[test]
+    fn written_entries_and_bytes_are_recorded_per_table() {
+        // given
+        let data = TestData::new(3);
+        let metrics = GenesisMetrics::default();
+        let reporter = ProgressReporter::default()
+            .with_genesis_metrics("coins".to_string(), metrics.clone());
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            TestHandler::new(|entry, tx| {
+                tx.storage_as_mut::<Coins>()
+                    .insert(&entry.key, &entry.value)?;
+                Ok(())
+            }),
+            data.as_ok_groups(),
+            Database::default(),
+            reporter,
+        );
+
+        // when
+        runner.run().unwrap();
+
+        // then
+        let table_metrics = metrics.table("coins").unwrap();
+        assert_eq!(table_metrics.entries_written, 3);
+        assert!(table_metrics.bytes_written > 0);
+        assert_eq!(table_metrics.entries_clamped, 0);
+    }
+
+    #[test]
+    fn progress_event_is_sent_after_each_committed_group() {
+        // given
+        let data = TestData::new(3);
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(10);
+        let progress = GenesisProgress::default().with_events(sender);
+        let reporter = ProgressReporter::default()
+            .with_genesis_progress("coins".to_string(), progress);
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            TestHandler::new(|_, _| Ok(())),
+            data.as_ok_groups(),
+            Database::default(),
+            reporter,
+        );
+
+        // when
+        runner.run().unwrap();
+
+        // then
+        let mut events = vec![];
+        while let Ok(event) = receiver.try_recv() {
+            events.push(event);
+        }
+        let progress: Vec<_> = events
+            .into_iter()
+            .inspect(|event| assert_eq!(event.table, "coins"))
+            .map(|event| {
+                (
+                    event.progress.groups_processed,
+                    event.progress.entries_written,
+                )
+            })
+            .collect();
+        assert_eq!(progress, vec![(1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn import_continues_when_the_progress_receiver_is_dropped() {
+        // given
+        let data = TestData::new(3);
+        let (sender, receiver) = tokio::sync::mpsc::channel(1);
+        drop(receiver);
+        let progress = GenesisProgress::default().with_events(sender);
+        let reporter = ProgressReporter::default()
+            .with_genesis_progress("coins".to_string(), progress.clone());
+        let runner = ImportTask::new(
+            CancellationToken::default(),
+            TestHandler::new(|_, _| Ok(())),
+            data.as_ok_groups(),
+            Database::default(),
+            reporter,
+        );
+
+        // when
+        let result = runner.run();
+
+        // then
+        result.unwrap();
+        assert_eq!(progress.table("coins").unwrap().groups_processed, 3);
+    }
+
     #[tokio::test]
     async fn processing_stops_when_cancelled() {
         // given
@@ -41,52 +41,50 @@ use fuel_core_types::services::executor::Event;
 use super::{
     import_task::ImportTable,
     Handler,
+    ImportSnapshotTable,
 };
 
-impl ImportTable for Handler<TransactionStatuses, TransactionStatuses> {
-    type TableInSnapshot = TransactionStatuses;
-    type TableBeingWritten = TransactionStatuses;
+impl ImportSnapshotTable for Handler<TransactionStatuses, TransactionStatuses> {
+    type Table = TransactionStatuses;
     type DbDesc = OffChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
         for tx_status in group {
-            tx.storage::<Self::TableInSnapshot>()
+            tx.storage::<Self::Table>()
                 .insert(&tx_status.key, &tx_status.value)?;
         }
         Ok(())
     }
 }
 
-impl ImportTable for Handler<FuelBlockIdsToHeights, FuelBlockIdsToHeights> {
-    type TableInSnapshot = FuelBlockIdsToHeights;
-    type TableBeingWritten = FuelBlockIdsToHeights;
+impl ImportSnapshotTable for Handler<FuelBlockIdsToHeights, FuelBlockIdsToHeights> {
+    type Table = FuelBlockIdsToHeights;
     type DbDesc = OffChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
         for entry in group {
-            tx.storage::<Self::TableInSnapshot>()
+            tx.storage::<Self::Table>()
                 .insert(&entry.key, &entry.value)?;
         }
         Ok(())
     }
 }
 
-impl ImportTable for Handler<OwnedTransactions, OwnedTransactions> {
-    type TableInSnapshot = OwnedTransactions;
-    type TableBeingWritten = OwnedTransactions;
+impl ImportSnapshotTable for Handler<OwnedTransactions, OwnedTransactions> {
+    type Table = OwnedTransactions;
     type DbDesc = OffChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
         for entry in group {
@@ -183,14 +181,13 @@ impl ImportTable for Handler<OldFuelBlocks, FuelBlocks> {
     }
 }
 
-impl ImportTable for Handler<OldFuelBlocks, OldFuelBlocks> {
-    type TableInSnapshot = OldFuelBlocks;
-    type TableBeingWritten = OldFuelBlocks;
+impl ImportSnapshotTable for Handler<OldFuelBlocks, OldFuelBlocks> {
+    type Table = OldFuelBlocks;
     type DbDesc = OffChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
         let blocks = group
@@ -219,14 +216,13 @@ impl ImportTable for Handler<OldFuelBlockConsensus, SealedBlockConsensus> {
     }
 }
 
-impl ImportTable for Handler<OldFuelBlockConsensus, OldFuelBlockConsensus> {
-    type TableInSnapshot = OldFuelBlockConsensus;
-    type TableBeingWritten = OldFuelBlockConsensus;
+impl ImportSnapshotTable for Handler<OldFuelBlockConsensus, OldFuelBlockConsensus> {
+    type Table = OldFuelBlockConsensus;
     type DbDesc = OffChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
         let blocks = group
@@ -255,14 +251,13 @@ impl ImportTable for Handler<OldTransactions, Transactions> {
     }
 }
 
-impl ImportTable for Handler<OldTransactions, OldTransactions> {
-    type TableInSnapshot = OldTransactions;
-    type TableBeingWritten = OldTransactions;
+impl ImportSnapshotTable for Handler<OldTransactions, OldTransactions> {
+    type Table = OldTransactions;
     type DbDesc = OffChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
         let transactions = group
@@ -1,12 +1,17 @@
 use super::{
-    import_task::ImportTable,
+    CoinTxPointerPolicy,
+    ContractStateCommits,
     Handler,
+    ImportSnapshotTable,
 };
-use crate::database::{
-    balances::BalancesInitializer,
-    database_description::on_chain::OnChain,
-    state::StateInitializer,
-    Database,
+use crate::{
+    database::{
+        balances::BalancesInitializer,
+        database_description::on_chain::OnChain,
+        state::StateInitializer,
+        Database,
+    },
+    service::genesis::coin_derived_from_message,
 };
 use anyhow::anyhow;
 use fuel_core_chain_config::TableEntry;
@@ -19,7 +24,10 @@ use fuel_core_storage::{
         ContractsState,
         Messages,
     },
-    transactional::StorageTransaction,
+    transactional::{
+        StorageTransaction,
+        WriteTransaction,
+    },
     StorageAsMut,
 };
 use fuel_core_types::{
@@ -29,50 +37,59 @@ use fuel_core_types::{
         coins::coin::Coin,
         Message,
     },
+    fuel_tx::TxPointer,
     fuel_types::BlockHeight,
 };
+use itertools::Itertools;
+use std::{
+    collections::HashSet,
+    num::NonZeroUsize,
+};
 
-impl ImportTable for Handler<Coins, Coins> {
-    type TableInSnapshot = Coins;
-    type TableBeingWritten = Coins;
+impl ImportSnapshotTable for Handler<Coins, Coins> {
+    type Table = Coins;
     type DbDesc = OnChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database>,
     ) -> anyhow::Result<()> {
+        ensure_unique_utxo_ids(&group)?;
         group.into_iter().try_for_each(|coin| {
-            init_coin(tx, &coin, self.block_height)?;
+            let clamped =
+                init_coin(tx, &coin, self.block_height, self.coin_tx_pointer_policy)?;
+            if clamped {
+                self.clamped_entries = self.clamped_entries.saturating_add(1);
+            }
             Ok(())
         })
     }
 }
 
-impl ImportTable for Handler<Messages, Messages> {
-    type TableInSnapshot = Messages;
-    type TableBeingWritten = Messages;
+impl ImportSnapshotTable for Handler<Messages, Messages> {
+    type Table = Messages;
     type DbDesc = OnChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database>,
     ) -> anyhow::Result<()> {
+        validate_messages(tx, &group, self.da_block_height)?;
         group
             .into_iter()
             .try_for_each(|message| init_da_message(tx, message, self.da_block_height))
     }
 }
 
-impl ImportTable for Handler<ContractsRawCode, ContractsRawCode> {
-    type TableInSnapshot = ContractsRawCode;
-    type TableBeingWritten = ContractsRawCode;
+impl ImportSnapshotTable for Handler<ContractsRawCode, ContractsRawCode> {
+    type Table = ContractsRawCode;
     type DbDesc = OnChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database>,
     ) -> anyhow::Result<()> {
         group.into_iter().try_for_each(|contract| {
@@ -82,14 +99,13 @@ impl ImportTable for Handler<ContractsRawCode, ContractsRawCode> {
     }
 }
 
-impl ImportTable for Handler<ContractsLatestUtxo, ContractsLatestUtxo> {
-    type TableInSnapshot = ContractsLatestUtxo;
-    type TableBeingWritten = ContractsLatestUtxo;
+impl ImportSnapshotTable for Handler<ContractsLatestUtxo, ContractsLatestUtxo> {
+    type Table = ContractsLatestUtxo;
     type DbDesc = OnChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database>,
     ) -> anyhow::Result<()> {
         group.into_iter().try_for_each(|contract| {
@@ -99,29 +115,50 @@ impl ImportTable for Handler<ContractsLatestUtxo, ContractsLatestUtxo> {
     }
 }
 
-impl ImportTable for Handler<ContractsState, ContractsState> {
-    type TableInSnapshot = ContractsState;
-    type TableBeingWritten = ContractsState;
+impl ImportSnapshotTable for Handler<ContractsState, ContractsState> {
+    type Table = ContractsState;
     type DbDesc = OnChain;
 
-    fn process(
+    fn split_group(
+        &self,
+        group: Vec<TableEntry<Self::Table>>,
+    ) -> Vec<Vec<TableEntry<Self::Table>>> {
+        match self.contract_state_commits {
+            ContractStateCommits::PerGroup => vec![group],
+            ContractStateCommits::PerContract => group
+                .into_iter()
+                .group_by(|entry| *entry.key.contract_id())
+                .into_iter()
+                .map(|(_, entries)| entries.collect())
+                .collect(),
+        }
+    }
+
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database>,
     ) -> anyhow::Result<()> {
-        tx.update_contract_states(group)?;
+        let chunk_size = self.chunk_size.map_or(group.len(), NonZeroUsize::get);
+
+        // Each chunk is flushed into the group transaction, so the group
+        // is still committed or discarded as a whole.
+        for chunk in &group.into_iter().chunks(chunk_size.max(1)) {
+            let mut chunk_tx = tx.write_transaction();
+            chunk_tx.update_contract_states(chunk)?;
+            chunk_tx.commit()?;
+        }
         Ok(())
     }
 }
 
-impl ImportTable for Handler<ContractsAssets, ContractsAssets> {
-    type TableInSnapshot = ContractsAssets;
-    type TableBeingWritten = ContractsAssets;
+impl ImportSnapshotTable for Handler<ContractsAssets, ContractsAssets> {
+    type Table = ContractsAssets;
     type DbDesc = OnChain;
 
-    fn process(
+    fn import_group(
         &mut self,
-        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        group: Vec<TableEntry<Self::Table>>,
         tx: &mut StorageTransaction<&mut Database>,
     ) -> anyhow::Result<()> {
         tx.update_contract_balances(group)?;
@@ -129,30 +166,58 @@ impl ImportTable for Handler<ContractsAssets, ContractsAssets> {
     }
 }
 
+/// Rejects the group if it contains the same `UtxoId` more than once.
+/// Duplicates across groups are caught by the storage insert in `init_coin`.
+fn ensure_unique_utxo_ids(group: &[TableEntry<Coins>]) -> anyhow::Result<()> {
+    let mut seen = HashSet::with_capacity(group.len());
+    for coin in group {
+        if !seen.insert(coin.key) {
+            return Err(anyhow!(
+                "coin with utxo id {:?} appears more than once in the same group",
+                coin.key
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Writes the coin into the database. Returns `true` if its `tx_pointer` was clamped
+/// to the genesis block.
 fn init_coin(
     transaction: &mut StorageTransaction<&mut Database>,
     coin: &TableEntry<Coins>,
     height: BlockHeight,
-) -> anyhow::Result<()> {
+    tx_pointer_policy: CoinTxPointerPolicy,
+) -> anyhow::Result<bool> {
     let utxo_id = coin.key;
 
+    // ensure coin isn't unspendable dust
+    if *coin.value.amount() == 0 {
+        return Err(anyhow!("coin with utxo id {utxo_id:?} has zero amount"));
+    }
+
+    // coins pointing to blocks in the future are rejected by `CoinTxPointerHeight`
+    // under the strict policy, the rest are clamped to the genesis block
+    let mut tx_pointer = *coin.value.tx_pointer();
+    let coin_height = tx_pointer.block_height();
+    let clamped =
+        coin_height > height && tx_pointer_policy == CoinTxPointerPolicy::ClampToGenesis;
+    if clamped {
+        tracing::warn!(
+            "Clamping the tx_pointer height ({coin_height}) of the coin with utxo id {utxo_id:?} to the genesis block ({height})"
+        );
+        tx_pointer = TxPointer::new(height, tx_pointer.tx_index());
+    }
+
     let compressed_coin = Coin {
         utxo_id,
         owner: *coin.value.owner(),
         amount: *coin.value.amount(),
         asset_id: *coin.value.asset_id(),
-        tx_pointer: *coin.value.tx_pointer(),
+        tx_pointer,
     }
     .compress();
 
-    // ensure coin can't point to blocks in the future
-    let coin_height = coin.value.tx_pointer().block_height();
-    if coin_height > height {
-        return Err(anyhow!(
-            "coin tx_pointer height ({coin_height}) cannot be greater than genesis block ({height})"
-        ));
-    }
-
     if transaction
         .storage::<Coins>()
         .insert(&utxo_id, &compressed_coin)?
@@ -161,7 +226,7 @@ fn init_coin(
         return Err(anyhow!("Coin should not exist"));
     }
 
-    Ok(())
+    Ok(clamped)
 }
 
 fn init_contract_latest_utxo(
@@ -207,6 +272,69 @@ fn init_contract_raw_code(
     Ok(())
 }
 
+/// Validates the whole group of messages before any of them is written.
+///
+/// The group is rejected if any message is above the genesis da height or if its nonce
+/// repeats within the group or was already imported, or if the coin derived from it
+/// is already imported, see [`coin_derived_from_message`]. The coins are imported
+/// before the messages, so every coin of the snapshot is checked on import.
+/// The validation doesn't commit the coins, so the derived coins aren't detected
+/// by it. All violations are reported in a single error. The maximum da height of the group is logged so operators
+/// can compare it with the expected relayer checkpoint.
+fn validate_messages(
+    transaction: &mut StorageTransaction<&mut Database>,
+    group: &[TableEntry<Messages>],
+    da_height: DaBlockHeight,
+) -> anyhow::Result<()> {
+    let mut seen = HashSet::with_capacity(group.len());
+    let mut violations = vec![];
+    let mut max_da_height = None;
+
+    for entry in group {
+        let nonce = entry.key;
+        let message_da_height = entry.value.da_height();
+        max_da_height = max_da_height.max(Some(message_da_height));
+
+        if message_da_height > da_height {
+            violations.push(format!(
+                "{nonce}: da_height ({message_da_height}) is greater than genesis da block height ({da_height})"
+            ));
+        }
+
+        // Only the messages with data are retryable and can carry a zero amount.
+        // A message without data is a coin, and an empty coin can't be spent.
+        if entry.value.amount() == 0 && entry.value.data().is_empty() {
+            violations.push(format!("{nonce}: has zero amount and no data"));
+        }
+
+        if !seen.insert(nonce) {
+            violations.push(format!("{nonce}: appears more than once in the group"));
+        } else if transaction.storage::<Messages>().contains_key(&nonce)? {
+            violations.push(format!("{nonce}: already imported"));
+        }
+
+        if let Some(utxo_id) = coin_derived_from_message(transaction, &nonce)? {
+            violations.push(format!("{nonce}: collides with the coin {utxo_id}"));
+        }
+    }
+
+    if let Some(max_da_height) = max_da_height {
+        tracing::info!(
+            "Messages group max da_height: {max_da_height}, genesis da_height: {da_height}"
+        );
+    }
+
+    if !violations.is_empty() {
+        return Err(anyhow!(
+            "{} invalid message(s) in the group:\n{}",
+            violations.len(),
+            violations.join("\n")
+        ));
+    }
+
+    Ok(())
+}
+
 fn init_da_message(
     transaction: &mut StorageTransaction<&mut Database>,
     msg: TableEntry<Messages>,
@@ -230,3 +358,379 @@ fn init_da_message(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::genesis::{
+        importer::{
+            import_task::{
+                ImportTable,
+                ImportTask,
+            },
+            progress::ProgressReporter,
+            GenesisValidationContext,
+            GenesisValidator,
+            GenesisValidators,
+            HandlerConfig,
+        },
+        task_manager::CancellationToken,
+    };
+    use fuel_core_chain_config::group_checksum;
+    use fuel_core_storage::{
+        tables::merkle::ContractsStateMerkleMetadata,
+        ContractsStateKey,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        fuel_tx::{
+            TxId,
+            UtxoId,
+        },
+        fuel_types::{
+            Bytes32,
+            ContractId,
+        },
+    };
+
+    fn contract_state_root(chunk_size: Option<NonZeroUsize>) -> Bytes32 {
+        let contract_id = ContractId::from([1; 32]);
+        let group = (0..10u8)
+            .map(|i| TableEntry {
+                key: ContractsStateKey::new(&contract_id, &[i; 32].into()),
+                value: vec![i].into(),
+            })
+            .collect();
+        let mut db = Database::default();
+        let mut tx = db.write_transaction();
+        let mut handler = Handler::<ContractsState, ContractsState>::new(
+            BlockHeight::default(),
+            DaBlockHeight::default(),
+        )
+        .with_config(HandlerConfig {
+            chunk_size,
+            ..Default::default()
+        });
+
+        handler.process(group, &mut tx).unwrap();
+
+        let metadata = tx
+            .storage::<ContractsStateMerkleMetadata>()
+            .get(&contract_id)
+            .unwrap()
+            .unwrap();
+        (*metadata.root()).into()
+    }
+
+    fn coin_at(height: u32) -> TableEntry<Coins> {
+        let coin = Coin {
+            utxo_id: Default::default(),
+            owner: Default::default(),
+            amount: 10,
+            asset_id: Default::default(),
+            tx_pointer: TxPointer::new(height.into(), 3),
+        };
+        TableEntry {
+            key: coin.utxo_id,
+            value: coin.compress(),
+        }
+    }
+
+    fn clamping_config() -> HandlerConfig {
+        HandlerConfig {
+            coin_tx_pointer_policy: CoinTxPointerPolicy::ClampToGenesis,
+            ..Default::default()
+        }
+    }
+
+    fn import_coin_with_checksum(checksum: Bytes32) -> anyhow::Result<()> {
+        let mut db = Database::default();
+        let mut handler =
+            Handler::<Coins, Coins>::new(5.into(), DaBlockHeight::default())
+                .with_group_checksums(Some(&[checksum]));
+        let mut tx = db.write_transaction();
+        handler.process(vec![coin_at(1)], &mut tx)?;
+        handler.record_written(&tx.into_changes())?;
+        handler.verify_written(0)
+    }
+
+    #[test]
+    fn written_group_matching_the_checksum_is_accepted() {
+        // Given
+        let checksum = group_checksum(&[coin_at(1)]).unwrap();
+
+        // When
+        let result = import_coin_with_checksum(checksum);
+
+        // Then
+        result.expect("The written coin matches the checksum");
+    }
+
+    #[test]
+    fn written_group_not_matching_the_checksum_is_rejected() {
+        // Given
+        let checksum = group_checksum(&[coin_at(2)]).unwrap();
+
+        // When
+        let result = import_coin_with_checksum(checksum);
+
+        // Then
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("Checksum mismatch for the group 0 of the table `Coins`"));
+    }
+
+    #[test]
+    fn coin_above_genesis_is_rejected_by_default() {
+        // Given
+        let handler = Handler::<Coins, Coins>::new(5.into(), DaBlockHeight::default());
+
+        // When
+        let result = handler.verify(0, &[coin_at(6)]);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn coin_above_genesis_is_accepted_without_default_validators() {
+        // Given
+        let handler = Handler::<Coins, Coins>::new(5.into(), DaBlockHeight::default())
+            .with_validators(GenesisValidators::empty());
+
+        // When
+        let result = handler.verify(0, &[coin_at(6)]);
+
+        // Then
+        assert!(result.is_ok());
+    }
+
+    struct RejectAmountsAbove(u64);
+
+    impl GenesisValidator<Coins> for RejectAmountsAbove {
+        fn validate_group(
+            &self,
+            _: &GenesisValidationContext,
+            group: &[TableEntry<Coins>],
+        ) -> anyhow::Result<()> {
+            if group.iter().any(|coin| *coin.value.amount() > self.0) {
+                anyhow::bail!("amount is above {}", self.0);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn registered_validator_is_called_for_the_group() {
+        // Given
+        let mut validators = GenesisValidators::default();
+        validators.register::<Coins>(RejectAmountsAbove(5));
+        let handler = Handler::<Coins, Coins>::new(5.into(), DaBlockHeight::default())
+            .with_validators(validators);
+
+        // When
+        let result = handler.verify(0, &[coin_at(1)]);
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert_eq!(err, "amount is above 5");
+    }
+
+    #[test]
+    fn coin_above_genesis_is_clamped_to_genesis_if_allowed() {
+        // Given
+        let mut db = Database::default();
+        let mut tx = db.write_transaction();
+        let mut handler =
+            Handler::<Coins, Coins>::new(5.into(), DaBlockHeight::default())
+                .with_config(clamping_config());
+        let coin = coin_at(6);
+
+        // When
+        handler.process(vec![coin.clone()], &mut tx).unwrap();
+
+        // Then
+        let stored = tx.storage::<Coins>().get(&coin.key).unwrap().unwrap();
+        assert_eq!(*stored.tx_pointer(), TxPointer::new(5.into(), 3));
+    }
+
+    #[test]
+    fn clamped_coins_are_counted_until_taken() {
+        // Given
+        let mut db = Database::default();
+        let mut tx = db.write_transaction();
+        let mut handler =
+            Handler::<Coins, Coins>::new(5.into(), DaBlockHeight::default())
+                .with_config(clamping_config());
+        handler.process(vec![coin_at(6)], &mut tx).unwrap();
+
+        // When
+        let clamped = handler.take_clamped_entries();
+
+        // Then
+        assert_eq!(clamped, 1);
+        assert_eq!(handler.take_clamped_entries(), 0);
+    }
+
+    #[test]
+    fn validation_verifies_the_checksum_of_the_groups_after_a_clamped_one() {
+        // Given
+        let clamped_coin = coin_at(6);
+        let coin = TableEntry {
+            key: UtxoId::new(TxId::from([1; 32]), 0),
+            ..coin_at(1)
+        };
+        let checksums = [
+            group_checksum(&[clamped_coin.clone()]).unwrap(),
+            group_checksum(&[coin_at(2)]).unwrap(),
+        ];
+        let handler = Handler::<Coins, Coins>::new(5.into(), DaBlockHeight::default())
+            .with_config(clamping_config())
+            .with_group_checksums(Some(&checksums));
+        let task = ImportTask::new(
+            CancellationToken::default(),
+            handler,
+            vec![Ok(vec![clamped_coin]), Ok(vec![coin])],
+            Database::default(),
+            ProgressReporter::default(),
+        );
+
+        // When
+        let errors = task.validate().unwrap();
+
+        // Then
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].group_index, 1);
+        assert!(errors[0]
+            .error
+            .to_string()
+            .contains("Checksum mismatch for the group 1 of the table `Coins`"));
+    }
+
+    fn message(nonce: u8, amount: u64, data: Vec<u8>) -> TableEntry<Messages> {
+        let mut message = Message::default();
+        message.set_nonce([nonce; 32].into());
+        message.set_amount(amount);
+        message.set_data(data);
+        TableEntry {
+            key: *message.nonce(),
+            value: message,
+        }
+    }
+
+    #[test]
+    fn message_with_zero_amount_and_no_data_is_rejected() {
+        // Given
+        let mut db = Database::default();
+        let mut tx = db.write_transaction();
+        let mut handler =
+            Handler::<Messages, Messages>::new(0.into(), DaBlockHeight::default());
+        let message = message(1, 0, vec![]);
+        let nonce = message.key;
+
+        // When
+        let result = handler.process(vec![message], &mut tx);
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains(&format!("{nonce}: has zero amount and no data")));
+    }
+
+    #[test]
+    fn message_with_zero_amount_and_data_is_imported() {
+        // Given
+        let mut db = Database::default();
+        let mut tx = db.write_transaction();
+        let mut handler =
+            Handler::<Messages, Messages>::new(0.into(), DaBlockHeight::default());
+        let message = message(1, 0, vec![1, 2, 3]);
+
+        // When
+        handler.process(vec![message.clone()], &mut tx).unwrap();
+
+        // Then
+        let stored = tx.storage::<Messages>().get(&message.key).unwrap().unwrap();
+        assert_eq!(stored.into_owned(), message.value);
+    }
+
+    #[test]
+    fn contract_state_is_split_into_commits_per_contract_if_requested() {
+        // Given
+        let group: Vec<_> = [1u8, 1, 2]
+            .into_iter()
+            .enumerate()
+            .map(|(i, contract)| TableEntry {
+                key: ContractsStateKey::new(
+                    &ContractId::from([contract; 32]),
+                    &Bytes32::from([u8::try_from(i).unwrap(); 32]),
+                ),
+                value: vec![contract].into(),
+            })
+            .collect();
+        let handler = Handler::<ContractsState, ContractsState>::new(
+            BlockHeight::default(),
+            DaBlockHeight::default(),
+        )
+        .with_config(HandlerConfig {
+            contract_state_commits: ContractStateCommits::PerContract,
+            ..Default::default()
+        });
+
+        // When
+        let parts = handler.split_into_commits(group.clone());
+
+        // Then
+        assert_eq!(parts, vec![group[..2].to_vec(), group[2..].to_vec()]);
+    }
+
+    #[test]
+    fn contract_state_chunks_are_not_committed_if_the_group_fails() {
+        // Given
+        let contract_id = ContractId::from([1; 32]);
+        let group: Vec<_> = (0..5u8)
+            .map(|i| TableEntry {
+                key: ContractsStateKey::new(&contract_id, &Bytes32::from([i; 32])),
+                value: vec![i].into(),
+            })
+            .collect();
+        let wrong_checksum = group_checksum(&group[..4]).unwrap();
+        let handler = Handler::<ContractsState, ContractsState>::new(
+            BlockHeight::default(),
+            DaBlockHeight::default(),
+        )
+        .with_config(HandlerConfig {
+            chunk_size: NonZeroUsize::new(2),
+            ..Default::default()
+        })
+        .with_group_checksums(Some(&[wrong_checksum]));
+        let db = Database::default();
+        let task = ImportTask::new(
+            CancellationToken::default(),
+            handler,
+            vec![Ok(group)],
+            db.clone(),
+            ProgressReporter::default(),
+        );
+
+        // When
+        let result = task.run();
+
+        // Then
+        assert!(result.is_err());
+        assert!(!db
+            .storage::<ContractsStateMerkleMetadata>()
+            .contains_key(&contract_id)
+            .unwrap());
+    }
+
+    #[test]
+    fn contract_state_chunks_produce_the_same_state_root() {
+        // Given
+        let chunk_size = NonZeroUsize::new(3);
+
+        // When
+        let chunked_root = contract_state_root(chunk_size);
+
+        // Then
+        assert_eq!(chunked_root, contract_state_root(None));
+    }
+}
@@ -1,3 +1,4 @@
+use fuel_core_services::SharedMutex;
 use indicatif::{
     HumanDuration,
     MultiProgress,
@@ -5,12 +6,171 @@ use indicatif::{
     ProgressDrawTarget,
     ProgressStyle,
 };
+use std::{
+    collections::BTreeMap,
+    fmt,
+    time::Duration,
+};
+use tokio::sync::mpsc;
 use tracing::Span;
 
+/// The progress of the import of one table from the snapshot.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TableProgress {
+    /// The number of groups processed so far. It includes the groups
+    /// processed before the restart of the import.
+    pub groups_processed: usize,
+    /// The total number of groups in the snapshot, if known.
+    pub total_groups: Option<usize>,
+    /// The number of entries written since the start of the current import.
+    pub entries_written: u64,
+}
+
+impl TableProgress {
+    /// Returns the percentage of processed groups if the total number of groups is known.
+    pub fn percentage(&self) -> Option<f64> {
+        let total_groups = self.total_groups?;
+        if total_groups == 0 {
+            return Some(100.0)
+        }
+        Some(self.groups_processed as f64 * 100.0 / total_groups as f64)
+    }
+}
+
+/// The progress of one table sent after each committed group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisProgressEvent {
+    /// The migration name of the table.
+    pub table: String,
+    /// The cumulative progress of the table after the commit.
+    pub progress: TableProgress,
+}
+
+/// The progress of the genesis import shared between the importer and the service.
+/// The progress is tracked per table using the migration name as a key.
+#[derive(Debug, Default, Clone)]
+pub struct GenesisProgress {
+    tables: SharedMutex<BTreeMap<String, TableProgress>>,
+    events: Option<mpsc::Sender<GenesisProgressEvent>>,
+}
+
+impl GenesisProgress {
+    /// Sends a [`GenesisProgressEvent`] into the `sender` after each committed group.
+    /// The importer never waits for the receiver: the events are dropped
+    /// if the channel is full or closed, and the import continues.
+    pub fn with_events(mut self, sender: mpsc::Sender<GenesisProgressEvent>) -> Self {
+        self.events = Some(sender);
+        self
+    }
+    /// Returns the progress of the table with the `name`.
+    pub fn table(&self, name: &str) -> Option<TableProgress> {
+        self.tables.lock().get(name).copied()
+    }
+
+    /// Returns the progress of all tables.
+    pub fn tables(&self) -> BTreeMap<String, TableProgress> {
+        self.tables.lock().clone()
+    }
+
+    fn update(&self, name: &str, f: impl FnOnce(&mut TableProgress)) {
+        self.tables
+            .apply(|tables| f(tables.entry(name.to_string()).or_default()))
+    }
+
+    fn notify(&self, name: &str) {
+        let Some(events) = &self.events else { return };
+        let Some(progress) = self.table(name) else {
+            return
+        };
+        let _ = events.try_send(GenesisProgressEvent {
+            table: name.to_string(),
+            progress,
+        });
+    }
+}
+
+/// The time spent on the import of one table from the snapshot and its outcome.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TableMetrics {
+    /// The total wall-clock time spent processing the groups.
+    pub processing_time: Duration,
+    /// The number of groups processed since the start of the current import.
+    pub groups_processed: usize,
+    /// The number of groups skipped because they were imported before the restart.
+    pub groups_skipped: usize,
+    /// The number of entries from the snapshot written into the database.
+    pub entries_written: u64,
+    /// The total size in bytes of the keys and values written into the database.
+    pub bytes_written: u64,
+    /// The number of entries adjusted to fit the genesis block before being written,
+    /// like the coins with the `tx_pointer` clamped to the genesis height.
+    pub entries_clamped: u64,
+}
+
+/// The timings of the genesis import collected per table using the migration name
+/// as a key. It is filled by the importer and can be read after the import completes.
+#[derive(Debug, Default, Clone)]
+pub struct GenesisMetrics {
+    tables: SharedMutex<BTreeMap<String, TableMetrics>>,
+}
+
+impl GenesisMetrics {
+    /// Returns the metrics of the table with the `name`.
+    pub fn table(&self, name: &str) -> Option<TableMetrics> {
+        self.tables.lock().get(name).copied()
+    }
+
+    /// Returns the metrics of all tables.
+    pub fn tables(&self) -> BTreeMap<String, TableMetrics> {
+        self.tables.lock().clone()
+    }
+
+    fn update(&self, name: &str, f: impl FnOnce(&mut TableMetrics)) {
+        self.tables
+            .apply(|tables| f(tables.entry(name.to_string()).or_default()))
+    }
+}
+
+impl fmt::Display for GenesisMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<60} {:>10} {:>12} {:>14} {:>16}",
+            "table", "groups", "entries", "bytes", "time"
+        )?;
+        for (name, table) in self.tables() {
+            writeln!(
+                f,
+                "{:<60} {:>10} {:>12} {:>14} {:>16.3?}",
+                name,
+                table.groups_processed,
+                table.entries_written,
+                table.bytes_written,
+                table.processing_time
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+struct TableProgressTracker {
+    name: String,
+    progress: GenesisProgress,
+}
+
+#[derive(Clone)]
+struct TableMetricsTracker {
+    name: String,
+    metrics: GenesisMetrics,
+}
+
 #[derive(Clone)]
 pub struct ProgressReporter {
     bar: ProgressBar,
     target: Target,
+    tracker: Option<TableProgressTracker>,
+    metrics: Option<TableMetricsTracker>,
 }
 
 impl Default for ProgressReporter {
@@ -42,12 +202,97 @@ impl ProgressReporter {
             bar.set_style(style);
         }
 
-        ProgressReporter { bar, target }
+        ProgressReporter {
+            bar,
+            target,
+            tracker: None,
+            metrics: None,
+        }
+    }
+
+    /// Reports the progress of the table with the `name` into the `progress`.
+    pub fn with_genesis_progress(
+        mut self,
+        name: String,
+        progress: GenesisProgress,
+    ) -> Self {
+        let total_groups = self.bar.length().and_then(|len| usize::try_from(len).ok());
+        progress.update(&name, |table| table.total_groups = total_groups);
+        self.tracker = Some(TableProgressTracker { name, progress });
+        self
+    }
+
+    /// Records the time spent on the groups of the table with the `name` into the `metrics`.
+    pub fn with_genesis_metrics(mut self, name: String, metrics: GenesisMetrics) -> Self {
+        self.metrics = Some(TableMetricsTracker { name, metrics });
+        self
+    }
+
+    /// Adds the time spent processing one group.
+    pub fn record_processing_time(&self, processing_time: Duration) {
+        self.update_metrics(|table| {
+            table.processing_time = table.processing_time.saturating_add(processing_time);
+            table.groups_processed = table.groups_processed.saturating_add(1);
+        });
+    }
+
+    /// Adds the `entries` of one group written into the database as `bytes`.
+    pub fn record_written(&self, entries: usize, bytes: u64) {
+        let entries = u64::try_from(entries).unwrap_or(u64::MAX);
+        self.update_metrics(|table| {
+            table.entries_written = table.entries_written.saturating_add(entries);
+            table.bytes_written = table.bytes_written.saturating_add(bytes);
+        });
+    }
+
+    /// Adds the entries adjusted to fit the genesis block before being written.
+    pub fn record_clamped(&self, entries: u64) {
+        self.update_metrics(|table| {
+            table.entries_clamped = table.entries_clamped.saturating_add(entries)
+        });
+    }
+
+    fn update_metrics(&self, f: impl FnOnce(&mut TableMetrics)) {
+        if let Some(tracker) = &self.metrics {
+            tracker.metrics.update(&tracker.name, f);
+        }
+    }
+
+    /// Sets the number of groups processed before the restart of the import.
+    pub fn resume_from(&self, groups_processed: usize) {
+        self.bar
+            .set_position(u64::try_from(groups_processed).unwrap_or(u64::MAX));
+        self.update_tracker(|table| table.groups_processed = groups_processed);
+        self.update_metrics(|table| table.groups_skipped = groups_processed);
+    }
+
+    /// Increases the number of entries written into the database.
+    pub fn add_entries_written(&self, entries: usize) {
+        let entries = u64::try_from(entries).unwrap_or(u64::MAX);
+        self.update_tracker(|table| {
+            table.entries_written = table.entries_written.saturating_add(entries)
+        });
+    }
+
+    /// Notifies the listeners of the progress about the group committed into the database.
+    pub fn report_committed(&self) {
+        if let Some(tracker) = &self.tracker {
+            tracker.progress.notify(&tracker.name);
+        }
+    }
+
+    fn update_tracker(&self, f: impl FnOnce(&mut TableProgress)) {
+        if let Some(tracker) = &self.tracker {
+            tracker.progress.update(&tracker.name, f);
+        }
     }
 
     pub fn set_progress(&self, group_index: u64) {
         let group_num = group_index.saturating_add(1);
         self.bar.set_position(group_num);
+        self.update_tracker(|table| {
+            table.groups_processed = usize::try_from(group_num).unwrap_or(usize::MAX)
+        });
         if let Target::Logs(span) = &self.target {
             span.in_scope(|| {
                 if let Some(len) = self.bar.length() {
@@ -85,6 +330,8 @@ impl MultipleProgressReporter {
         ProgressReporter {
             bar,
             target: reporter.target,
+            tracker: reporter.tracker,
+            metrics: reporter.metrics,
         }
     }
 }
@@ -0,0 +1,121 @@
+use crate::graphql_api::storage::{
+    coins::OwnedCoins,
+    messages::OwnedMessageIds,
+};
+use fuel_core_storage::{
+    kv_store::StorageColumn,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+    },
+};
+use itertools::Itertools;
+use std::collections::BTreeSet;
+
+/// The tables of the snapshot imported at genesis.
+///
+/// The tables derived from a table of the snapshot, like the off-chain indexes
+/// of the coins and messages, are imported only if that table is in the scope.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportScope {
+    /// The column names of the imported tables of the snapshot,
+    /// or `None` if all tables are imported.
+    tables: Option<BTreeSet<&'static str>>,
+    /// The column names of the off-chain indexes that aren't built from the snapshot.
+    skipped_indexes: BTreeSet<&'static str>,
+}
+
+impl ImportScope {
+    /// The scope importing all tables of the snapshot.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// The scope importing no tables, to be extended with [`Self::with_table`].
+    pub fn empty() -> Self {
+        Self {
+            tables: Some(BTreeSet::new()),
+            ..Self::default()
+        }
+    }
+
+    /// The scope importing only the bytecode, the latest UTXOs, the state
+    /// and the balances of the contracts.
+    pub fn contracts() -> Self {
+        Self::empty()
+            .with_table::<ContractsRawCode>()
+            .with_table::<ContractsLatestUtxo>()
+            .with_table::<ContractsState>()
+            .with_table::<ContractsAssets>()
+    }
+
+    /// Adds the table `T` of the snapshot to the scope.
+    /// The scope importing all tables is left as is.
+    pub fn with_table<T: TableWithBlueprint>(mut self) -> Self {
+        if let Some(tables) = &mut self.tables {
+            tables.insert(T::column().name());
+        }
+        self
+    }
+
+    /// Returns whether the table `T` of the snapshot is imported.
+    pub fn contains<T: TableWithBlueprint>(&self) -> bool {
+        self.contains_name(T::column().name())
+    }
+
+    /// Skips building the off-chain indexes of the coins and messages by their owner
+    /// from the imported `Coins` and `Messages`, for the nodes rebuilding them on
+    /// their own. Until then, the coins and messages can't be queried by the owner.
+    pub fn without_owner_indexes(mut self) -> Self {
+        self.skipped_indexes.insert(OwnedCoins::column().name());
+        self.skipped_indexes
+            .insert(OwnedMessageIds::column().name());
+        self
+    }
+
+    /// Returns whether the off-chain index `T` is built from the snapshot.
+    pub fn builds_index<T: TableWithBlueprint>(&self) -> bool {
+        !self.skipped_indexes.contains(T::column().name())
+    }
+
+    fn contains_name(&self, name: &str) -> bool {
+        self.tables
+            .as_ref()
+            .map_or(true, |tables| tables.contains(name))
+    }
+
+    /// Returns an error listing the tables of the scope that can't be imported
+    /// without the tables they depend on, if they are not in the scope.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let missing = dependencies()
+            .into_iter()
+            .filter(|(table, dependency)| {
+                self.contains_name(table) && !self.contains_name(dependency)
+            })
+            .map(|(table, dependency)| format!("`{table}` requires `{dependency}`"))
+            .collect_vec();
+
+        if !missing.is_empty() {
+            anyhow::bail!("The import scope is inconsistent: {}", missing.join(", "));
+        }
+        Ok(())
+    }
+}
+
+/// The pairs of tables where the first one can only be imported with the second one.
+///
+/// Every contract is identified by its latest UTXO, which is only meaningful
+/// together with the bytecode of the contract.
+fn dependencies() -> [(&'static str, &'static str); 4] {
+    let raw_code = ContractsRawCode::column().name();
+    let latest_utxo = ContractsLatestUtxo::column().name();
+    [
+        (raw_code, latest_utxo),
+        (latest_utxo, raw_code),
+        (ContractsState::column().name(), latest_utxo),
+        (ContractsAssets::column().name(), latest_utxo),
+    ]
+}
@@ -0,0 +1,160 @@
+use super::CoinTxPointerPolicy;
+use anyhow::anyhow;
+use fuel_core_chain_config::TableEntry;
+use fuel_core_storage::{
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        ContractsRawCode,
+    },
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::BlockHeight,
+};
+use std::{
+    any::{
+        Any,
+        TypeId,
+    },
+    collections::HashMap,
+    sync::Arc,
+};
+
+/// The parameters of the genesis that the groups are validated against.
+#[derive(Debug, Clone, Copy)]
+pub struct GenesisValidationContext {
+    /// The height of the genesis block.
+    pub block_height: BlockHeight,
+    /// The DA height of the genesis block.
+    pub da_block_height: DaBlockHeight,
+    /// The maximum size of the contract bytecode allowed by the consensus parameters.
+    pub contract_max_size: u64,
+    /// The handling of the coins with the `tx_pointer` above the genesis block.
+    pub coin_tx_pointer_policy: CoinTxPointerPolicy,
+}
+
+/// Validates the groups of the table `T` before they are imported at genesis.
+pub trait GenesisValidator<T: TableWithBlueprint>: Send + Sync {
+    /// Called for every group of the table before the group is written.
+    /// An error rejects the group. Accepts every group by default.
+    fn validate_group(
+        &self,
+        _context: &GenesisValidationContext,
+        _group: &[TableEntry<T>],
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// The validators of the on-chain tables, registered on the importer.
+///
+/// The default set contains the built-in validators of the genesis rules.
+#[derive(Clone)]
+pub struct GenesisValidators {
+    /// Maps the `TypeId` of the table `T` into `Vec<Arc<dyn GenesisValidator<T>>>`.
+    validators: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl GenesisValidators {
+    /// Creates the set without any validators, including the built-in ones.
+    pub fn empty() -> Self {
+        Self {
+            validators: HashMap::new(),
+        }
+    }
+
+    /// Registers the `validator` of the table `T`. The validators of the table
+    /// run in the order of the registration.
+    pub fn register<T>(&mut self, validator: impl GenesisValidator<T> + 'static)
+    where
+        T: TableWithBlueprint + 'static,
+    {
+        let mut validators = self.of::<T>();
+        validators.push(Arc::new(validator));
+        self.validators
+            .insert(TypeId::of::<T>(), Arc::new(validators));
+    }
+
+    /// Returns the validators of the table `T`.
+    pub fn of<T>(&self) -> Vec<Arc<dyn GenesisValidator<T>>>
+    where
+        T: TableWithBlueprint + 'static,
+    {
+        self.validators
+            .get(&TypeId::of::<T>())
+            .and_then(|validators| {
+                validators.downcast_ref::<Vec<Arc<dyn GenesisValidator<T>>>>()
+            })
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+impl Default for GenesisValidators {
+    fn default() -> Self {
+        let mut validators = Self::empty();
+        validators.register::<Coins>(CoinTxPointerHeight);
+        validators.register::<ContractsRawCode>(ContractCodeSize);
+        validators
+    }
+}
+
+impl core::fmt::Debug for GenesisValidators {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GenesisValidators")
+            .field("tables", &self.validators.len())
+            .finish()
+    }
+}
+
+/// Rejects the coins with the `tx_pointer` above the genesis block,
+/// unless the [`CoinTxPointerPolicy`] allows clamping them.
+pub struct CoinTxPointerHeight;
+
+impl GenesisValidator<Coins> for CoinTxPointerHeight {
+    fn validate_group(
+        &self,
+        context: &GenesisValidationContext,
+        group: &[TableEntry<Coins>],
+    ) -> anyhow::Result<()> {
+        if context.coin_tx_pointer_policy != CoinTxPointerPolicy::Strict {
+            return Ok(())
+        }
+
+        let height = context.block_height;
+        for coin in group {
+            let coin_height = coin.value.tx_pointer().block_height();
+            if coin_height > height {
+                return Err(anyhow!(
+                    "coin tx_pointer height ({coin_height}) cannot be greater than genesis block ({height})"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rejects the contracts with the bytecode above the maximum contract size.
+pub struct ContractCodeSize;
+
+impl GenesisValidator<ContractsRawCode> for ContractCodeSize {
+    fn validate_group(
+        &self,
+        context: &GenesisValidationContext,
+        group: &[TableEntry<ContractsRawCode>],
+    ) -> anyhow::Result<()> {
+        let contract_max_size = context.contract_max_size;
+        for entry in group {
+            let contract_id = entry.key;
+            let size = u64::try_from(entry.value.as_ref().len()).unwrap_or(u64::MAX);
+            if size > contract_max_size {
+                return Err(anyhow!(
+                    "The code of the contract {contract_id} has size {size} bytes, \
+                    which exceeds the maximum contract size of {contract_max_size} bytes"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -235,6 +235,7 @@ pub fn init_sub_services(
         max_tx: config.txpool.max_tx,
         max_depth: config.txpool.max_depth,
         chain_name,
+        read_view: config.read_view,
     };
 
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
@@ -261,6 +262,7 @@ pub fn init_sub_services(
         relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
         graph_ql: graph_ql.shared.clone(),
         database,
+        genesis_progress: Default::default(),
         block_importer: importer_adapter,
         config: config.clone(),
     };
@@ -38,6 +38,20 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
         height: Option<Height>,
         changes: Changes,
     ) -> StorageResult<()>;
+
+    /// Returns the approximate size of the `column` in bytes.
+    ///
+    /// By default, the sizes of all keys and values of the column are summed up,
+    /// which requires iterating over the whole column.
+    fn column_size(&self, column: Self::Column) -> StorageResult<u64> {
+        self.iter_store(column, None, None, IterDirection::Forward)
+            .try_fold(0u64, |size, item| {
+                let (key, value) = item?;
+                let entry_size = u64::try_from(key.len().saturating_add(value.len()))
+                    .unwrap_or(u64::MAX);
+                Ok(size.saturating_add(entry_size))
+            })
+    }
 }
 
 // It is used only to allow conversion of the `StorageTransaction` into the `DataSource`.
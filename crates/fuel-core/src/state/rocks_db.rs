@@ -555,6 +555,21 @@ where
             .write(batch)
             .map_err(|e| DatabaseError::Other(e.into()).into())
     }
+
+    /// Returns the size of the SST files and the memtables of the column family,
+    /// as estimated by the RocksDB without reading the data.
+    fn column_size(&self, column: Description::Column) -> StorageResult<u64> {
+        let cf = self.cf(column);
+        let property = |name: &str| {
+            self.db
+                .property_int_value_cf(&cf, name)
+                .map(|value| value.unwrap_or_default())
+                .map_err(|e| DatabaseError::Other(e.into()))
+        };
+        let sst_files = property("rocksdb.total-sst-files-size")?;
+        let memtables = property("rocksdb.size-all-mem-tables")?;
+        Ok(sst_files.saturating_add(memtables))
+    }
 }
 
 /// The `None` means overflow, so there is not following prefix.
@@ -48,6 +48,7 @@ async fn coin() {
     let coin = CoinConfig {
         output_index,
         tx_id,
+        amount: 1,
         ..Default::default()
     };
 
@@ -21,6 +21,25 @@ async fn health() {
     assert!(health);
 }
 
+#[tokio::test]
+async fn readiness() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+
+    // When
+    let response = reqwest::get(format!("http://{}/v1/readiness", srv.bound_address))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let body: serde_json::Value =
+        serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(body, serde_json::json!({ "ready": true }));
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
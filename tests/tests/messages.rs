@@ -63,11 +63,13 @@ async fn messages_returns_messages_for_all_owners() {
     let first_msg = MessageConfig {
         recipient: owner_a,
         nonce: 1.into(),
+        amount: 10,
         ..Default::default()
     };
     let second_msg = MessageConfig {
         recipient: owner_a,
         nonce: 2.into(),
+        amount: 10,
         ..Default::default()
     };
 
@@ -75,6 +77,7 @@ async fn messages_returns_messages_for_all_owners() {
     let third_msg = MessageConfig {
         recipient: owner_b,
         nonce: 3.into(),
+        amount: 10,
         ..Default::default()
     };
 
@@ -108,11 +111,13 @@ async fn messages_by_owner_returns_messages_for_the_given_owner() {
     let first_msg = MessageConfig {
         recipient: owner_a,
         nonce: 1.into(),
+        amount: 10,
         ..Default::default()
     };
     let second_msg = MessageConfig {
         recipient: owner_a,
         nonce: 2.into(),
+        amount: 10,
         ..Default::default()
     };
 
@@ -120,6 +125,7 @@ async fn messages_by_owner_returns_messages_for_the_given_owner() {
     let third_msg = MessageConfig {
         recipient: owner_b,
         nonce: 3.into(),
+        amount: 10,
         ..Default::default()
     };
 
@@ -583,6 +589,7 @@ async fn can_get_message() {
     let first_msg = MessageConfig {
         recipient: owner,
         nonce: 1.into(),
+        amount: 10,
         ..Default::default()
     };
 
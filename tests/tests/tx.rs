@@ -501,6 +501,44 @@ async fn get_transactions_by_owner_supports_cursor(direction: PageDirection) {
     );
 }
 
+#[test_case::test_case(PageDirection::Forward; "forward")]
+#[test_case::test_case(PageDirection::Backward; "backward")]
+#[tokio::test]
+async fn get_transactions_by_owner_reports_page_info(direction: PageDirection) {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+
+    let mut context = TestContext::new(100).await;
+    for amount in 1..=5 {
+        let _ = context.transfer(alice, bob, amount).await.unwrap();
+    }
+
+    let client = context.client;
+
+    let first_page = PaginationRequest {
+        cursor: None,
+        results: 3,
+        direction,
+    };
+    let response = client
+        .transactions_by_owner(&bob, first_page)
+        .await
+        .unwrap();
+    assert_eq!(response.results.len(), 3);
+    assert!(!response.has_previous_page);
+    assert!(response.has_next_page);
+
+    let last_page = PaginationRequest {
+        cursor: response.cursor,
+        results: 3,
+        direction,
+    };
+    let response = client.transactions_by_owner(&bob, last_page).await.unwrap();
+    assert_eq!(response.results.len(), 2);
+    assert!(response.has_previous_page);
+    assert!(!response.has_next_page);
+}
+
 #[tokio::test]
 async fn get_transactions_from_manual_blocks() {
     let context = TestContext::new(100).await;